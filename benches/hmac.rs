@@ -0,0 +1,30 @@
+//! Compares HMAC-SHA256 with a precomputed key schedule against computing the
+//! key schedule for every message.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use sha2_const::hmac::{HmacSha256, PrecomputedHmacSha256};
+
+const KEY: &[u8] = b"benchmark key";
+const MESSAGE: &[u8] = b"a short message, as in a typical request signature";
+
+fn hmac(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hmac_sha256");
+
+    group.bench_function("naive", |b| {
+        b.iter(|| {
+            HmacSha256::new(black_box(KEY))
+                .update(black_box(MESSAGE))
+                .finalize()
+        })
+    });
+
+    let key = PrecomputedHmacSha256::new(KEY);
+    group.bench_function("precomputed", |b| {
+        b.iter(|| black_box(&key).mac(black_box(MESSAGE)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, hmac);
+criterion_main!(benches);
@@ -0,0 +1,152 @@
+//! Hash chains and other linear combinations of digests.
+
+use crate::Sha256;
+
+/// Computes a hash chain of length `N` starting from `seed`.
+///
+/// The returned array is `[seed, H(seed), H(H(seed)), ...]`, where `H` is
+/// SHA-256. In a one-time password scheme, the last element is published as
+/// the anchor and earlier elements are revealed in reverse order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::chain::hash_chain;
+/// const CHAIN: [[u8; 32]; 4] = hash_chain(&[0; 32]);
+///
+/// assert_eq!(CHAIN[0], [0; 32]);
+/// assert_eq!(
+///     hex::encode(&CHAIN[3][..]),
+///     "12771355e46cd47c71ed1721fd5319b383cca3a1f9fce3aa1c8cd3bd37af20d7"
+/// );
+/// ```
+#[must_use]
+pub const fn hash_chain<const N: usize>(seed: &[u8; 32]) -> [[u8; 32]; N] {
+    let mut chain = [[0; 32]; N];
+    if N == 0 {
+        return chain;
+    }
+
+    chain[0] = *seed;
+    let mut i = 1;
+    while i < N {
+        chain[i] = Sha256::new().update(&chain[i - 1]).finalize();
+        i += 1;
+    }
+
+    chain
+}
+
+/// Computes the SHA-256 hash of the concatenation of `digests`.
+///
+/// This combines a list of digests, such as per-file hashes, into a single
+/// digest. The number of digests is not bound into the result; see
+/// [`fold_digests_counted`] for a variant that does.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{chain::fold_digests, Sha256};
+/// const DIGESTS: [[u8; 32]; 3] = [
+///     Sha256::new().update(b"a").finalize(),
+///     Sha256::new().update(b"b").finalize(),
+///     Sha256::new().update(b"c").finalize(),
+/// ];
+/// const ROOT: [u8; 32] = fold_digests(&DIGESTS);
+///
+/// assert_eq!(
+///     hex::encode(&ROOT[..]),
+///     "3a050f1d08fb8581d3d72ae727651e981043de0d6ca8e744328758f716602beb"
+/// );
+/// ```
+#[must_use]
+pub const fn fold_digests(digests: &[[u8; 32]]) -> [u8; 32] {
+    fold(Sha256::new(), digests)
+}
+
+/// Computes the SHA-256 hash of the number of digests, encoded as a 64-bit big
+/// endian integer, followed by the concatenation of `digests`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{chain::fold_digests_counted, Sha256};
+/// const DIGESTS: [[u8; 32]; 3] = [
+///     Sha256::new().update(b"a").finalize(),
+///     Sha256::new().update(b"b").finalize(),
+///     Sha256::new().update(b"c").finalize(),
+/// ];
+/// const ROOT: [u8; 32] = fold_digests_counted(&DIGESTS);
+///
+/// assert_eq!(
+///     hex::encode(&ROOT[..]),
+///     "8ef85aecf761fa826c9283cfa4860080aaf0add2dd8bd6aa73653713b2958508"
+/// );
+/// ```
+#[must_use]
+pub const fn fold_digests_counted(digests: &[[u8; 32]]) -> [u8; 32] {
+    fold(
+        Sha256::new().update(&(digests.len() as u64).to_be_bytes()),
+        digests,
+    )
+}
+
+/// Links `record` to the previous tip of a hash-linked log, returning
+/// `SHA-256(prev || record)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::chain::chain_records;
+/// const TIP: [u8; 32] = chain_records(&[0; 32], b"genesis");
+///
+/// assert_eq!(
+///     hex::encode(&TIP[..]),
+///     "ee7fad6509a3606f2a3b4a4023976dbb001a3eecbd16e127f2fb9641e7e4ecce"
+/// );
+/// ```
+#[must_use]
+pub const fn chain_records(prev: &[u8; 32], record: &[u8]) -> [u8; 32] {
+    Sha256::new().update(prev).update(record).finalize()
+}
+
+/// Computes the tip of a hash-linked log by applying [`chain_records`] to each
+/// of `records` in turn, starting from `genesis`.
+///
+/// If `records` is empty, `genesis` is returned unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::chain::chain_all;
+/// const TIP: [u8; 32] = chain_all(
+///     &[0; 32],
+///     &[b"genesis", b"alice pays bob", b"bob pays carol"],
+/// );
+///
+/// assert_eq!(
+///     hex::encode(&TIP[..]),
+///     "c875631eb1794db5a8f51c04cb569671e1857aec6e4f97c831595578abb82f30"
+/// );
+/// ```
+#[must_use]
+pub const fn chain_all(genesis: &[u8; 32], records: &[&[u8]]) -> [u8; 32] {
+    let mut tip = *genesis;
+    let mut i = 0;
+    while i < records.len() {
+        tip = chain_records(&tip, records[i]);
+        i += 1;
+    }
+
+    tip
+}
+
+const fn fold(mut hash: Sha256, digests: &[[u8; 32]]) -> [u8; 32] {
+    let mut i = 0;
+    while i < digests.len() {
+        hash = hash.update(&digests[i]);
+        i += 1;
+    }
+
+    hash.finalize()
+}
@@ -0,0 +1,430 @@
+//! Predicates and lookups on digests for use in compile-time checks.
+
+use crate::{
+    util::{ct_eq, hex_digit, memcpy, memeq},
+    Sha256,
+};
+
+/// Returns `true` if the last `suffix.len()` bytes of `digest` are equal to
+/// `suffix`.
+///
+/// Returns `false` if `suffix` is longer than `digest`. An empty `suffix`
+/// matches every digest.
+///
+/// Combined with a `const fn` loop over candidate nonces, this can be used to
+/// search for a digest with a particular suffix at compile time. Every
+/// candidate costs a full hash computation, so only small search spaces are
+/// practical before hitting the compiler's const evaluation limits.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{check::has_suffix, Sha256};
+/// const fn find_nonce(suffix: &[u8]) -> u32 {
+///     let mut nonce: u32 = 0;
+///     loop {
+///         let digest = Sha256::new()
+///             .update(b"vanity")
+///             .update(&nonce.to_be_bytes())
+///             .finalize();
+///         if has_suffix(&digest, suffix) {
+///             return nonce;
+///         }
+///         nonce += 1;
+///     }
+/// }
+///
+/// const NONCE: u32 = find_nonce(&[0x00]);
+///
+/// assert_eq!(NONCE, 150);
+/// ```
+#[must_use]
+pub const fn has_suffix<const N: usize>(digest: &[u8; N], suffix: &[u8]) -> bool {
+    if suffix.len() > N {
+        return false;
+    }
+
+    let offset = N - suffix.len();
+    let mut i = 0;
+    while i < suffix.len() {
+        if digest[offset + i] != suffix[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Returns `true` if every byte of `digest` is zero.
+///
+/// A digest that is entirely zero almost certainly was never computed, so this
+/// is useful for catching miswired constants.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{check::is_all_zero, Sha256};
+/// const DIGEST: [u8; 32] = Sha256::new().update(b"data").finalize();
+/// const _: () = assert!(!is_all_zero(&DIGEST));
+///
+/// assert!(is_all_zero(&[0u8; 32]));
+/// ```
+#[must_use]
+pub const fn is_all_zero<const N: usize>(digest: &[u8; N]) -> bool {
+    let mut i = 0;
+    while i < N {
+        if digest[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Returns `true` if any byte of `digest` is non-zero.
+///
+/// This is the negation of [`is_all_zero`].
+#[must_use]
+pub const fn is_nonzero<const N: usize>(digest: &[u8; N]) -> bool {
+    !is_all_zero(digest)
+}
+
+/// Returns the number of set bits in `digest`.
+///
+/// For a uniformly random digest, this is close to half the number of bits.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{check::popcount, Sha256};
+/// const WEIGHT: u32 = popcount(&Sha256::new().update(b"abc").finalize());
+///
+/// assert_eq!(WEIGHT, 120);
+/// ```
+#[must_use]
+pub const fn popcount<const N: usize>(digest: &[u8; N]) -> u32 {
+    let mut count = 0;
+    let mut i = 0;
+    while i < N {
+        count += digest[i].count_ones();
+        i += 1;
+    }
+
+    count
+}
+
+/// Returns the number of occurrences of each byte value in `digest`.
+///
+/// # Panics
+///
+/// Panics if `N` is greater than 255, since a count could then overflow.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{check::byte_histogram, Sha256};
+/// const HISTOGRAM: [u8; 256] = byte_histogram(&Sha256::new().update(b"abc").finalize());
+///
+/// assert_eq!(HISTOGRAM[0x41], 2);
+/// assert_eq!(HISTOGRAM[0xff], 1);
+/// assert_eq!(HISTOGRAM[0xee], 0);
+/// ```
+#[must_use]
+pub const fn byte_histogram<const N: usize>(digest: &[u8; N]) -> [u8; 256] {
+    assert!(N <= 255);
+
+    let mut histogram = [0; 256];
+    let mut i = 0;
+    while i < N {
+        histogram[digest[i] as usize] += 1;
+        i += 1;
+    }
+
+    histogram
+}
+
+/// Returns the number of leading bits shared by `SHA-256(a)` and `SHA-256(b)`.
+///
+/// This is useful for demonstrating how quickly collisions appear in truncated
+/// hashes. Identical inputs share all 256 bits.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::check::collide_bits;
+/// const SHARED: u32 = collide_bits(b"hello", &291u32.to_be_bytes());
+///
+/// assert_eq!(SHARED, 11);
+/// assert_eq!(collide_bits(b"hello", b"world"), 1);
+/// assert_eq!(collide_bits(b"hello", b"hello"), 256);
+/// ```
+#[must_use]
+pub const fn collide_bits(a: &[u8], b: &[u8]) -> u32 {
+    let a = Sha256::new().update(a).finalize();
+    let b = Sha256::new().update(b).finalize();
+
+    let mut bits = 0;
+    let mut i = 0;
+    while i < a.len() {
+        let diff = a[i] ^ b[i];
+        if diff != 0 {
+            return bits + diff.leading_zeros();
+        }
+        bits += 8;
+        i += 1;
+    }
+
+    bits
+}
+
+/// Hashes `input` with SHA-256 and returns the value associated with the
+/// matching digest in `table`.
+///
+/// The table is searched linearly, and the value of the first matching entry
+/// is returned. Returns `None` if no entry matches.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{check::lookup_by_hash, Sha256};
+/// const TABLE: [([u8; 32], u32); 2] = [
+///     (Sha256::new().update(b"/index.html").finalize(), 200),
+///     (Sha256::new().update(b"/missing.html").finalize(), 404),
+/// ];
+/// const STATUS: Option<u32> = lookup_by_hash(b"/index.html", &TABLE);
+///
+/// assert_eq!(STATUS, Some(200));
+/// assert_eq!(lookup_by_hash(b"/other.html", &TABLE), None);
+/// ```
+#[must_use]
+pub const fn lookup_by_hash(input: &[u8], table: &[([u8; 32], u32)]) -> Option<u32> {
+    let digest = Sha256::new().update(input).finalize();
+
+    let mut i = 0;
+    while i < table.len() {
+        if memeq(&table[i].0, &digest) {
+            return Some(table[i].1);
+        }
+        i += 1;
+    }
+
+    None
+}
+
+/// Returns `true` if any two entries of `digests` are equal.
+///
+/// Every pair of entries is compared, so the cost grows quadratically with the
+/// length of `digests`. A duplicate in a table of precomputed digests usually
+/// indicates a copy and paste mistake in the source data.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{check::has_duplicate, Sha256};
+/// const TABLE: [[u8; 32]; 3] = [
+///     Sha256::new().update(b"a").finalize(),
+///     Sha256::new().update(b"b").finalize(),
+///     Sha256::new().update(b"a").finalize(),
+/// ];
+/// const DUPLICATE: bool = has_duplicate(&TABLE);
+///
+/// assert!(DUPLICATE);
+/// assert!(!has_duplicate(&TABLE[..2]));
+/// ```
+#[must_use]
+pub const fn has_duplicate(digests: &[[u8; 32]]) -> bool {
+    let mut i = 0;
+    while i < digests.len() {
+        let mut j = i + 1;
+        while j < digests.len() {
+            if memeq(&digests[i], &digests[j]) {
+                return true;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+
+    false
+}
+
+/// Returns `true` if `expected` is the digest of the manifest entry for `path`
+/// with contents `content`.
+///
+/// The digest is `SHA-256(path || 0x00 || content)`. Binding the path means
+/// that the contents of one entry cannot be substituted for another, even if
+/// both are listed in the manifest. The comparison is constant-time.
+///
+/// # Panics
+///
+/// Panics if `path` contains a NUL character, which would make the encoding
+/// ambiguous.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{check::verify_entry, Sha256};
+/// const EXPECTED: [u8; 32] = Sha256::new()
+///     .update(b"assets/logo.svg\0")
+///     .update(b"<svg/>")
+///     .finalize();
+/// const VALID: bool = verify_entry("assets/logo.svg", b"<svg/>", &EXPECTED);
+///
+/// assert!(VALID);
+/// assert!(!verify_entry("assets/icon.svg", b"<svg/>", &EXPECTED));
+/// ```
+#[must_use]
+pub const fn verify_entry(path: &str, content: &[u8], expected: &[u8; 32]) -> bool {
+    let path = path.as_bytes();
+    let mut i = 0;
+    while i < path.len() {
+        assert!(path[i] != 0);
+        i += 1;
+    }
+
+    let digest = Sha256::new()
+        .update(path)
+        .update(&[0])
+        .update(content)
+        .finalize();
+    ct_eq(&digest, expected)
+}
+
+/// Panics, with a message naming `name` and showing the first bytes of both
+/// digests, unless `digest` is equal to the hex string `expected`.
+///
+/// `expected` may use uppercase or lowercase hex digits. This function is
+/// intended to be evaluated in a `const` item, usually through
+/// [`const_verify!`](crate::const_verify), so that a mismatch is reported as a
+/// compile error.
+///
+/// # Panics
+///
+/// Panics if `expected` is not a hex encoding of `N` bytes, or if it does not
+/// match `digest`.
+pub const fn assert_digest<const N: usize>(name: &str, digest: &[u8; N], expected: &str) {
+    let expected = expected.as_bytes();
+    if expected.len() != N * 2 {
+        panic!("expected digest has the wrong length");
+    }
+
+    let mut equal = true;
+    let mut i = 0;
+    while i < N {
+        let byte = (expected_digit(expected[2 * i]) << 4) | expected_digit(expected[2 * i + 1]);
+        equal &= byte == digest[i];
+        i += 1;
+    }
+
+    if !equal {
+        mismatch(name, digest, expected);
+    }
+}
+
+/// The number of leading digest bytes shown in a mismatch message.
+const SHOWN_BYTES: usize = 8;
+
+/// The maximum length of the algorithm name shown in a mismatch message.
+const MAX_NAME_LEN: usize = 64;
+
+const fn mismatch(name: &str, digest: &[u8], expected: &[u8]) -> ! {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    const EXPECTED: &[u8] = b" digest mismatch: expected ";
+    const COMPUTED: &[u8] = b"..., computed ";
+
+    let mut message = [0; MAX_NAME_LEN + 2 * SHOWN_BYTES * 2 + 64];
+    let mut len = 0;
+
+    // Type names are ASCII, so truncating never splits a UTF-8 sequence.
+    let name_len = if name.len() < MAX_NAME_LEN {
+        name.len()
+    } else {
+        MAX_NAME_LEN
+    };
+    memcpy(&mut message, len, name.as_bytes(), 0, name_len);
+    len += name_len;
+    memcpy(&mut message, len, EXPECTED, 0, EXPECTED.len());
+    len += EXPECTED.len();
+
+    let shown = if digest.len() < SHOWN_BYTES {
+        digest.len()
+    } else {
+        SHOWN_BYTES
+    };
+    let mut i = 0;
+    while i < shown * 2 {
+        message[len] = expected[i].to_ascii_lowercase();
+        len += 1;
+        i += 1;
+    }
+
+    memcpy(&mut message, len, COMPUTED, 0, COMPUTED.len());
+    len += COMPUTED.len();
+
+    let mut i = 0;
+    while i < shown {
+        message[len] = HEX[(digest[i] >> 4) as usize];
+        message[len + 1] = HEX[(digest[i] & 0xf) as usize];
+        len += 2;
+        i += 1;
+    }
+    message[len] = b'.';
+    message[len + 1] = b'.';
+    message[len + 2] = b'.';
+    len += 3;
+
+    let (message, _) = message.split_at(len);
+    match core::str::from_utf8(message) {
+        Ok(message) => panic!("{}", message),
+        Err(_) => panic!("digest mismatch"),
+    }
+}
+
+const fn expected_digit(c: u8) -> u8 {
+    match hex_digit(c) {
+        Some(digit) => digit,
+        None => panic!("expected digest is not valid hex"),
+    }
+}
+
+/// Asserts at compile time that the digest of some data is equal to an
+/// expected hex string.
+///
+/// `const_verify!(Type, data, "expected")` hashes `data` with `Type` and
+/// checks the result with [`assert_digest`](crate::check::assert_digest). On a
+/// mismatch, the compile error names `Type` and shows the first few bytes of
+/// the expected and computed digests.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{const_verify, Sha256};
+/// const_verify!(
+///     Sha256,
+///     b"abc",
+///     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+/// );
+/// ```
+///
+/// A mismatch fails to compile:
+///
+/// ```rust,compile_fail
+/// # use sha2_const::{const_verify, Sha256};
+/// const_verify!(
+///     Sha256,
+///     b"abd",
+///     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+/// );
+/// ```
+#[macro_export]
+macro_rules! const_verify {
+    ($hash:ty, $data:expr, $expected:expr $(,)?) => {
+        const _: () = $crate::check::assert_digest(
+            stringify!($hash),
+            &<$hash>::new().update($data).finalize(),
+            $expected,
+        );
+    };
+}
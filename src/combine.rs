@@ -0,0 +1,35 @@
+//! Hash combiners built from more than one SHA-2 variant.
+
+use crate::{Sha256, Sha512};
+
+/// Computes `SHA-256(SHA-256(input) || SHA-512(input))`.
+///
+/// # Security
+///
+/// Two inputs only produce the same output if they collide in both SHA-256
+/// and SHA-512, or if the two 96-byte concatenations collide in SHA-256. The
+/// second case is a collision between fixed-length inputs that are themselves
+/// digests, so an attack on SHA-256 that needs control over the message
+/// structure does not directly apply. This is weaker than a true combiner: the
+/// result is compressed to 256 bits by SHA-256, so it cannot be more collision
+/// resistant than SHA-256 on 96-byte inputs. If that matters, store the two
+/// digests side by side instead.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::combine::multi_hash;
+/// const DIGEST: [u8; 32] = multi_hash(b"The quick brown fox jumps over the lazy dog");
+///
+/// assert_eq!(
+///     hex::encode(&DIGEST[..]),
+///     "b80c61fb1ccb86abfb36c27b29b50c776d3c7126a18084b3b5a83b574c593fac"
+/// );
+/// ```
+#[must_use]
+pub const fn multi_hash(input: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .update(&Sha256::new().update(input).finalize())
+        .update(&Sha512::new().update(input).finalize())
+        .finalize()
+}
@@ -1,12 +1,17 @@
-pub(crate) const H224: [u32; 8] = [
+//! Constants of the SHA-2 family of hash functions, as specified by FIPS 180-4.
+
+/// The SHA-224 initialization vector (FIPS 180-4, section 5.3.2).
+pub const H224: [u32; 8] = [
     0xc1059ed8, 0x367cd507, 0x3070dd17, 0xf70e5939, 0xffc00b31, 0x68581511, 0x64f98fa7, 0xbefa4fa4,
 ];
 
-pub(crate) const H256: [u32; 8] = [
+/// The SHA-256 initialization vector (FIPS 180-4, section 5.3.3).
+pub const H256: [u32; 8] = [
     0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
 ];
 
-pub(crate) const H384: [u64; 8] = [
+/// The SHA-384 initialization vector (FIPS 180-4, section 5.3.4).
+pub const H384: [u64; 8] = [
     0xcbbb9d5dc1059ed8,
     0x629a292a367cd507,
     0x9159015a3070dd17,
@@ -17,7 +22,8 @@ pub(crate) const H384: [u64; 8] = [
     0x47b5481dbefa4fa4,
 ];
 
-pub(crate) const H512: [u64; 8] = [
+/// The SHA-512 initialization vector (FIPS 180-4, section 5.3.5).
+pub const H512: [u64; 8] = [
     0x6a09e667f3bcc908,
     0xbb67ae8584caa73b,
     0x3c6ef372fe94f82b,
@@ -28,7 +34,11 @@ pub(crate) const H512: [u64; 8] = [
     0x5be0cd19137e2179,
 ];
 
-pub(crate) const H512_224: [u64; 8] = [
+/// The SHA-512/224 initialization vector (FIPS 180-4, section 5.3.6.1).
+///
+/// This is precomputed; it is equal to
+/// [`sha512t_iv(224)`](crate::sha512t_iv).
+pub const H512_224: [u64; 8] = [
     0x8c3d37c819544da2,
     0x73e1996689dcd4d6,
     0x1dfab7ae32ff9c82,
@@ -39,7 +49,11 @@ pub(crate) const H512_224: [u64; 8] = [
     0x1112e6ad91d692a1,
 ];
 
-pub(crate) const H512_256: [u64; 8] = [
+/// The SHA-512/256 initialization vector (FIPS 180-4, section 5.3.6.2).
+///
+/// This is precomputed; it is equal to
+/// [`sha512t_iv(256)`](crate::sha512t_iv).
+pub const H512_256: [u64; 8] = [
     0x22312194fc2bf72c,
     0x9f555fa3c84c64c2,
     0x2393b86b6f53b151,
@@ -50,7 +64,11 @@ pub(crate) const H512_256: [u64; 8] = [
     0x0eb72ddc81c52ca2,
 ];
 
-pub(crate) const K256: [u32; 64] = [
+/// The SHA-224 and SHA-256 round constants (FIPS 180-4, section 4.2.2).
+///
+/// These are the first 32 bits of the fractional parts of the cube roots of
+/// the first 64 prime numbers.
+pub const K256: [u32; 64] = [
     0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
     0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
     0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
@@ -61,7 +79,12 @@ pub(crate) const K256: [u32; 64] = [
     0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
 ];
 
-pub(crate) const K512: [u64; 80] = [
+/// The SHA-384, SHA-512, SHA-512/224 and SHA-512/256 round constants
+/// (FIPS 180-4, section 4.2.3).
+///
+/// These are the first 64 bits of the fractional parts of the cube roots of
+/// the first 80 prime numbers.
+pub const K512: [u64; 80] = [
     0x428a2f98d728ae22,
     0x7137449123ef65cd,
     0xb5c0fbcfec4d3b2f,
@@ -143,3 +166,33 @@ pub(crate) const K512: [u64; 80] = [
     0x5fcb6fab3ad6faec,
     0x6c44198c4a475817,
 ];
+
+pub(crate) const DIGEST_INFO_224: &[u8] = &[
+    0x30, 0x2d, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x04, 0x05,
+    0x00, 0x04, 0x1c,
+];
+
+pub(crate) const DIGEST_INFO_256: &[u8] = &[
+    0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01, 0x05,
+    0x00, 0x04, 0x20,
+];
+
+pub(crate) const DIGEST_INFO_384: &[u8] = &[
+    0x30, 0x41, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02, 0x05,
+    0x00, 0x04, 0x30,
+];
+
+pub(crate) const DIGEST_INFO_512: &[u8] = &[
+    0x30, 0x51, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03, 0x05,
+    0x00, 0x04, 0x40,
+];
+
+pub(crate) const DIGEST_INFO_512_224: &[u8] = &[
+    0x30, 0x2d, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x05, 0x05,
+    0x00, 0x04, 0x1c,
+];
+
+pub(crate) const DIGEST_INFO_512_256: &[u8] = &[
+    0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x06, 0x05,
+    0x00, 0x04, 0x20,
+];
@@ -0,0 +1,473 @@
+//! Deterministic derivation of values from SHA-256.
+
+use crate::{
+    util::{load_u32_be, load_u64_be, memcpy},
+    Sha256,
+};
+
+/// Derives a table of `N` salts from `master`.
+///
+/// The salt at index `i` is `SHA-256(master || i)`, where `i` is encoded as a
+/// 64-bit big endian integer.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::derive::salt_table;
+/// const SALTS: [[u8; 32]; 3] = salt_table(b"master salt");
+///
+/// assert_eq!(
+///     hex::encode(&SALTS[0][..]),
+///     "1613d89ee070f5370c342840939eefd0b6e1d075420d4faf76c649a038d458a1"
+/// );
+/// assert_eq!(
+///     hex::encode(&SALTS[2][..]),
+///     "ffc9063d244988d3af90aba4bb1bfa1983532332743ab6d6f259f8d9efd0871b"
+/// );
+/// ```
+#[must_use]
+pub const fn salt_table<const N: usize>(master: &[u8]) -> [[u8; 32]; N] {
+    let mut table = [[0; 32]; N];
+
+    let mut i = 0;
+    while i < N {
+        table[i] = Sha256::new()
+            .update(master)
+            .update(&(i as u64).to_be_bytes())
+            .finalize();
+        i += 1;
+    }
+
+    table
+}
+
+/// Maps `name` to an index into a palette of `PALETTE` colors.
+///
+/// The index is the first four bytes of `SHA-256(name)`, interpreted as a
+/// 32-bit big endian integer, modulo `PALETTE`. This mapping is stable, so the
+/// same name is assigned the same color across builds and platforms. Unless
+/// `PALETTE` is a power of two, lower indices are very slightly more likely.
+///
+/// # Panics
+///
+/// Panics if `PALETTE` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::derive::color_seed;
+/// const ALICE: u32 = color_seed::<12>(b"alice");
+/// const BOB: u32 = color_seed::<12>(b"bob");
+///
+/// assert_eq!(ALICE, 1);
+/// assert_eq!(BOB, 0);
+/// ```
+#[must_use]
+pub const fn color_seed<const PALETTE: u32>(name: &[u8]) -> u32 {
+    assert!(PALETTE != 0);
+
+    let digest = Sha256::new().update(name).finalize();
+    load_u32_be(&digest, 0) % PALETTE
+}
+
+/// Derives an `L`-byte nonce from `key_context` and `counter`.
+///
+/// The nonce is the first `L` bytes of `SHA-256(key_context || counter)`, where
+/// `counter` is encoded as a 64-bit big endian integer. Typical values of `L`
+/// are 12 (ChaCha20-Poly1305, AES-GCM) and 16.
+///
+/// The caller is responsible for never reusing a `(key_context, counter)` pair
+/// with the same key.
+///
+/// # Panics
+///
+/// Panics if `L` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::derive::derive_nonce;
+/// const NONCE: [u8; 12] = derive_nonce(b"session 1", 7);
+///
+/// assert_eq!(hex::encode(&NONCE[..]), "42b91f7e98dd93df3de09a98");
+/// ```
+#[must_use]
+pub const fn derive_nonce<const L: usize>(key_context: &[u8], counter: u64) -> [u8; L] {
+    assert!(L <= 32);
+
+    let digest = Sha256::new()
+        .update(key_context)
+        .update(&counter.to_be_bytes())
+        .finalize();
+    let mut nonce = [0; L];
+    memcpy(&mut nonce, 0, &digest, 0, L);
+    nonce
+}
+
+/// Computes a salted password verifier by iterating SHA-256 `ROUNDS` times.
+///
+/// The first round computes `h = SHA-256(salt || password)`, and each
+/// subsequent round computes `h = SHA-256(h || salt || password)`.
+///
+/// This simple iterated construction is provided to reproduce legacy formats
+/// exactly. It is considerably weaker than a dedicated password hashing
+/// function such as PBKDF2, and should not be used for new designs. Each round
+/// costs a full hash computation, so large values of `ROUNDS` may exceed the
+/// compiler's const evaluation limits.
+///
+/// # Panics
+///
+/// Panics if `ROUNDS` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::derive::simple_verifier;
+/// const SALT: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+/// const VERIFIER: [u8; 32] = simple_verifier::<64>(b"hunter2", &SALT);
+///
+/// assert_eq!(
+///     hex::encode(&VERIFIER[..]),
+///     "5741cbfa5d85f4bcf5828a46877b997f874ba22b0a88740b73889cb4a4878e74"
+/// );
+/// ```
+#[must_use]
+pub const fn simple_verifier<const ROUNDS: u32>(password: &[u8], salt: &[u8; 16]) -> [u8; 32] {
+    assert!(ROUNDS != 0);
+
+    let mut h = Sha256::new().update(salt).update(password).finalize();
+    let mut i = 1;
+    while i < ROUNDS {
+        h = Sha256::new()
+            .update(&h)
+            .update(salt)
+            .update(password)
+            .finalize();
+        i += 1;
+    }
+
+    h
+}
+
+/// Derives a numeric code of up to `digits` decimal digits from `input`.
+///
+/// The code is the first four bytes of `SHA-256(input)`, interpreted as a
+/// 32-bit big endian integer with the most significant bit cleared, modulo
+/// `10^digits`. This mirrors the dynamic truncation used by HOTP (RFC 4226).
+///
+/// The 31-bit value is not an exact multiple of `10^digits`, so smaller codes
+/// are slightly more likely. The bias is negligible for six digits, but for
+/// nine digits, codes below 147,483,648 are about 1.5 times as likely as the
+/// rest.
+///
+/// # Panics
+///
+/// Panics if `digits` is greater than 9.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::derive::numeric_code;
+/// const CODE: u32 = numeric_code(b"user@example.com", 6);
+///
+/// assert_eq!(CODE, 629577);
+/// ```
+#[must_use]
+pub const fn numeric_code(input: &[u8], digits: u32) -> u32 {
+    assert!(digits <= 9);
+
+    let digest = Sha256::new().update(input).finalize();
+    (load_u32_be(&digest, 0) & 0x7fffffff) % 10u32.pow(digits)
+}
+
+/// Derives a stable 64-bit tag for an enum variant from its name.
+///
+/// The tag is the first eight bytes of `SHA-256(name)`, where `name` is hashed
+/// as UTF-8, interpreted as a 64-bit big endian integer. Unlike a declaration
+/// order discriminant, the tag does not change when variants are added,
+/// removed or reordered.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::derive::variant_tag;
+/// const CREATED: u64 = variant_tag("Created");
+///
+/// assert_eq!(CREATED, 0xd70b9e24bca26b40);
+/// ```
+#[must_use]
+pub const fn variant_tag(name: &str) -> u64 {
+    let digest = Sha256::new().update(name.as_bytes()).finalize();
+    load_u64_be(&digest, 0)
+}
+
+/// Selects `WORDS` words from `dict` to form a human-comparable fingerprint of
+/// `digest`.
+///
+/// The word at index `i` is `dict[digest[i] % dict.len()]`. Each word encodes
+/// at most eight bits of the digest, and only does so fully if `dict` has 256
+/// words. If the length of `dict` does not divide 256, words near the start of
+/// `dict` are slightly more likely.
+///
+/// # Panics
+///
+/// Panics if `dict` is empty or `WORDS` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{derive::wordlist_fingerprint, Sha256};
+/// const DICT: [&str; 8] = [
+///     "apple", "banana", "cherry", "grape", "lemon", "mango", "olive", "peach",
+/// ];
+/// const DIGEST: [u8; 32] = Sha256::new().update(b"ssh-ed25519 AAAA").finalize();
+/// const FINGERPRINT: [&str; 4] = wordlist_fingerprint(&DIGEST, &DICT);
+///
+/// assert_eq!(FINGERPRINT, ["mango", "apple", "cherry", "banana"]);
+/// ```
+#[must_use]
+pub const fn wordlist_fingerprint<'a, const WORDS: usize>(
+    digest: &[u8; 32],
+    dict: &[&'a str],
+) -> [&'a str; WORDS] {
+    assert!(!dict.is_empty() && WORDS <= 32);
+
+    let mut words = [""; WORDS];
+    let mut i = 0;
+    while i < WORDS {
+        words[i] = dict[digest[i] as usize % dict.len()];
+        i += 1;
+    }
+
+    words
+}
+
+/// Derives a deterministic sort key for `item`.
+///
+/// The key is the first eight bytes of `SHA-256(item)`, interpreted as a 64-bit
+/// big endian integer. Sorting by this key gives an order that appears random
+/// but is the same across builds and platforms.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::derive::order_key;
+/// const KEY: u64 = order_key(b"alpha");
+///
+/// assert_eq!(KEY, 0x8ed3f6ad685b959e);
+/// ```
+#[must_use]
+pub const fn order_key(item: &[u8]) -> u64 {
+    let digest = Sha256::new().update(item).finalize();
+    load_u64_be(&digest, 0)
+}
+
+/// Returns the indices of `items` sorted by their [`order_key`].
+///
+/// The sort is stable, so items with equal keys (in particular, equal items)
+/// keep their relative order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::derive::sorted_indices;
+/// const ORDER: [usize; 5] = sorted_indices(&[b"alpha", b"bravo", b"charlie", b"delta", b"echo"]);
+///
+/// assert_eq!(ORDER, [4, 3, 0, 2, 1]);
+/// ```
+#[must_use]
+pub const fn sorted_indices<const N: usize>(items: &[&[u8]; N]) -> [usize; N] {
+    let mut keys = [0; N];
+    let mut indices = [0; N];
+    let mut i = 0;
+    while i < N {
+        keys[i] = order_key(items[i]);
+        indices[i] = i;
+        i += 1;
+    }
+
+    // Insertion sort, moving the keys along with the indices.
+    let mut i = 1;
+    while i < N {
+        let key = keys[i];
+        let index = indices[i];
+        let mut j = i;
+        while j > 0 && keys[j - 1] > key {
+            keys[j] = keys[j - 1];
+            indices[j] = indices[j - 1];
+            j -= 1;
+        }
+        keys[j] = key;
+        indices[j] = index;
+        i += 1;
+    }
+
+    indices
+}
+
+/// Maps `input` to a position on a `width` by `height` grid.
+///
+/// The x coordinate is the first four bytes of `SHA-256(input)`, and the y
+/// coordinate is the next four bytes, each interpreted as a 32-bit big endian
+/// integer and reduced modulo the corresponding dimension. As with
+/// [`color_seed`], smaller coordinates are very slightly more likely unless
+/// the dimension is a power of two.
+///
+/// # Panics
+///
+/// Panics if `width` or `height` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::derive::grid_position;
+/// const POSITION: (u32, u32) = grid_position(b"treasure chest", 64, 48);
+///
+/// assert_eq!(POSITION, (50, 46));
+/// ```
+#[must_use]
+pub const fn grid_position(input: &[u8], width: u32, height: u32) -> (u32, u32) {
+    assert!(width != 0 && height != 0);
+
+    let digest = Sha256::new().update(input).finalize();
+    (
+        load_u32_be(&digest, 0) % width,
+        load_u32_be(&digest, 4) % height,
+    )
+}
+
+/// Derives a pseudorandom permutation of `0..N` from `seed`.
+///
+/// The permutation is generated by a Fisher-Yates shuffle of the identity
+/// permutation. The shuffle draws bytes from the concatenation of the blocks
+/// `SHA-256(seed || counter)`, where `counter` is encoded as a 64-bit big
+/// endian integer starting at zero. For each index `i` from `N - 1` down to
+/// 1, entry `i` is swapped with the entry indexed by the next byte modulo
+/// `i + 1`. Bytes that would make this biased are rejected and redrawn.
+///
+/// # Panics
+///
+/// Panics if `N` is greater than 256.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::derive::derive_permutation;
+/// const TABLE: [u8; 16] = derive_permutation(b"sbox");
+///
+/// assert_eq!(
+///     TABLE,
+///     [15, 13, 12, 10, 11, 5, 4, 3, 7, 6, 9, 14, 1, 0, 8, 2]
+/// );
+/// ```
+#[must_use]
+pub const fn derive_permutation<const N: usize>(seed: &[u8]) -> [u8; N] {
+    assert!(N <= 256);
+
+    let mut table = [0; N];
+    let mut i = 0;
+    while i < N {
+        table[i] = i as u8;
+        i += 1;
+    }
+
+    let mut stream = ByteStream::new(seed);
+    let mut i = N;
+    while i > 1 {
+        i -= 1;
+        let bound = (i + 1) as u32;
+        let limit = 256 - 256 % bound;
+        let mut byte = stream.next() as u32;
+        while byte >= limit {
+            byte = stream.next() as u32;
+        }
+        let j = (byte % bound) as usize;
+
+        let tmp = table[i];
+        table[i] = table[j];
+        table[j] = tmp;
+    }
+
+    table
+}
+
+/// A stream of bytes from the blocks `SHA-256(seed || counter)`.
+struct ByteStream<'a> {
+    seed: &'a [u8],
+    block: [u8; 32],
+    counter: u64,
+    offset: usize,
+}
+
+impl<'a> ByteStream<'a> {
+    const fn new(seed: &'a [u8]) -> Self {
+        Self {
+            seed,
+            block: [0; 32],
+            counter: 0,
+            offset: 32,
+        }
+    }
+
+    const fn next(&mut self) -> u8 {
+        if self.offset == self.block.len() {
+            self.block = Sha256::new()
+                .update(self.seed)
+                .update(&self.counter.to_be_bytes())
+                .finalize();
+            self.counter += 1;
+            self.offset = 0;
+        }
+
+        let byte = self.block[self.offset];
+        self.offset += 1;
+        byte
+    }
+}
+
+/// A truncated SHA-256 digest, for use as a compact key.
+///
+/// The ordering is lexicographic on the truncated bytes, so `ShortHash` can be
+/// used as a key in both hash maps and ordered maps.
+///
+/// # Collisions
+///
+/// Truncation reduces collision resistance to about `L * 4` bits: with `L` of
+/// 8, a collision among roughly four billion inputs is more likely than not.
+/// For small `L`, collisions should be expected between unrelated inputs, and
+/// even for larger `L`, they can be found deliberately by anyone who controls
+/// the inputs.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::derive::ShortHash;
+/// const KEY: ShortHash<8> = ShortHash::new(b"config.toml");
+///
+/// assert_eq!(hex::encode(KEY.as_bytes()), "28043ff911f28a5c");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ShortHash<const L: usize>([u8; L]);
+
+impl<const L: usize> ShortHash<L> {
+    /// Computes the first `L` bytes of `SHA-256(input)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `L` is greater than 32.
+    #[must_use]
+    pub const fn new(input: &[u8]) -> Self {
+        assert!(L <= 32);
+
+        let digest = Sha256::new().update(input).finalize();
+        let mut bytes = [0; L];
+        memcpy(&mut bytes, 0, &digest, 0, L);
+        Self(bytes)
+    }
+
+    /// Returns the truncated digest.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; L] {
+        &self.0
+    }
+}
@@ -0,0 +1,155 @@
+//! A digest wrapper type with formatting support.
+
+#[cfg(feature = "serde")]
+use crate::util::hex_digit;
+use core::{fmt, ops::Deref};
+
+/// An `N`-byte digest.
+///
+/// This wraps the array returned by `finalize`, adding hex formatting: both
+/// [`Display`](fmt::Display) and [`LowerHex`](fmt::LowerHex) render lowercase
+/// hex, and [`UpperHex`](fmt::UpperHex) renders uppercase hex. The alternate
+/// flag (`{:#x}`) adds a `0x` prefix. The bytes can be accessed through
+/// [`Deref`] or [`AsRef`].
+///
+/// With the `serde` feature, digests are serialized as hex strings in
+/// human-readable formats such as JSON, and as bytes in binary formats.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{digest::Digest, Sha256};
+/// const DIGEST: Digest<32> = Sha256::new().update(b"abc").finalize_digest();
+///
+/// assert_eq!(
+///     DIGEST.to_string(),
+///     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+/// );
+/// assert_eq!(DIGEST[0], 0xba);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Digest<const N: usize>([u8; N]);
+
+impl<const N: usize> Digest<N> {
+    /// Wrap `bytes` as a digest.
+    pub const fn new(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the bytes of the digest.
+    #[must_use]
+    pub const fn into_bytes(self) -> [u8; N] {
+        self.0
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for Digest<N> {
+    fn from(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for Digest<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> Deref for Digest<N> {
+    type Target = [u8; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const N: usize> fmt::Display for Digest<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl<const N: usize> fmt::LowerHex for Digest<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> fmt::UpperHex for Digest<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        for byte in &self.0 {
+            write!(f, "{:02X}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for Digest<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for Digest<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(DigestVisitor)
+        } else {
+            deserializer.deserialize_bytes(DigestVisitor)
+        }
+    }
+}
+
+/// Deserializes a digest from a hex string or from bytes.
+#[cfg(feature = "serde")]
+struct DigestVisitor<const N: usize>;
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::de::Visitor<'de> for DigestVisitor<N> {
+    type Value = Digest<N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a {}-byte digest", N)
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let hex = v.as_bytes();
+        if hex.len() != N * 2 {
+            return Err(E::invalid_length(hex.len() / 2, &self));
+        }
+
+        let mut bytes = [0; N];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            match (hex_digit(hex[2 * i]), hex_digit(hex[2 * i + 1])) {
+                (Some(hi), Some(lo)) => *byte = (hi << 4) | lo,
+                _ => return Err(E::invalid_value(serde::de::Unexpected::Str(v), &self)),
+            }
+        }
+        Ok(Digest(bytes))
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        if v.len() != N {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(v);
+        Ok(Digest(bytes))
+    }
+}
@@ -0,0 +1,29 @@
+//! Git object identifiers.
+
+use crate::{util::u64_to_decimal, Sha256};
+
+/// Computes the object ID of a blob in a Git repository using the SHA-256
+/// object format.
+///
+/// Git hashes a blob as `"blob " || len || "\0" || content`, where `len` is the
+/// length of `content` in bytes, formatted as ASCII decimal.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::git::git_blob_oid;
+/// const OID: [u8; 32] = git_blob_oid(b"hello world\n");
+///
+/// assert_eq!(
+///     hex::encode(&OID[..]),
+///     "0bd69098bd9b9cc5934a610ab65da429b525361147faa7b5b922919e9a23143d"
+/// );
+/// ```
+#[must_use]
+pub const fn git_blob_oid(content: &[u8]) -> [u8; 32] {
+    let (digits, len) = u64_to_decimal(content.len() as u64);
+
+    let mut hash = Sha256::new().update(b"blob ");
+    hash.inner.update_range(&digits, 0, len);
+    hash.update(&[0]).update(content).finalize()
+}
@@ -0,0 +1,88 @@
+//! HMAC-based key derivation function (RFC 5869) with SHA-256.
+
+use crate::{
+    hmac::{HmacSha256, PrecomputedHmacSha256},
+    util::memcpy,
+};
+
+/// The maximum output length of [`hkdf_expand`], 255 blocks of 32 bytes.
+const MAX_OUTPUT_LEN: usize = 255 * 32;
+
+/// Computes the HKDF-Extract step, returning a pseudorandom key.
+///
+/// The pseudorandom key is `HMAC-SHA256(salt, ikm)`. An empty `salt` is
+/// equivalent to a salt of 32 zero bytes, as specified by RFC 5869.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::hkdf::hkdf_extract;
+/// const PRK: [u8; 32] = hkdf_extract(b"salt", b"input key material");
+///
+/// assert_eq!(
+///     hex::encode(&PRK[..]),
+///     "4d6ccd983bd6ccd07adae83b28bb09e6d82b6d62bb7241f0dd6b111b2b5359a6"
+/// );
+/// ```
+#[must_use]
+pub const fn hkdf_extract(salt: &[u8], ikm: &[u8]) -> [u8; 32] {
+    HmacSha256::new(salt).update(ikm).finalize()
+}
+
+/// Computes the HKDF-Expand step, deriving `L` bytes of output keying material
+/// from the pseudorandom key `prk` and the context `info`.
+///
+/// The output is the first `L` bytes of `T(1) || T(2) || ...`, where `T(0)` is
+/// empty and `T(i) = HMAC-SHA256(prk, T(i - 1) || info || i)`, with `i`
+/// encoded as a single byte. Each block costs two hash computations, so large
+/// values of `L` may exceed the compiler's const evaluation limits.
+///
+/// # Panics
+///
+/// Panics if `L` is greater than 8160 (255 blocks of 32 bytes).
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::hkdf::{hkdf_expand, hkdf_extract};
+/// const PRK: [u8; 32] = hkdf_extract(b"salt", b"input key material");
+/// const OKM: [u8; 42] = hkdf_expand(&PRK, b"context");
+///
+/// assert_eq!(
+///     hex::encode(&OKM[..]),
+///     concat!(
+///         "790773b8093544d7052c18034ec05ddbf2753a2b9a23783a868b95143f516357",
+///         "25de7645b8581740526b"
+///     )
+/// );
+/// ```
+#[must_use]
+pub const fn hkdf_expand<const L: usize>(prk: &[u8], info: &[u8]) -> [u8; L] {
+    assert!(L <= MAX_OUTPUT_LEN);
+
+    let key = PrecomputedHmacSha256::new(prk);
+    let mut okm = [0; L];
+    let mut block = [0; 32];
+    let mut offset = 0;
+    let mut counter: u8 = 1;
+    while offset < L {
+        let inner = if counter == 1 {
+            key.inner()
+        } else {
+            key.inner().update(&block)
+        };
+        block = key.finalize(&inner.update(info).update(&[counter]).finalize());
+
+        let n = if L - offset < block.len() {
+            L - offset
+        } else {
+            block.len()
+        };
+        memcpy(&mut okm, offset, &block, 0, n);
+        offset += n;
+        // With the maximum output length, this only wraps after the last block.
+        counter = counter.wrapping_add(1);
+    }
+
+    okm
+}
@@ -0,0 +1,333 @@
+//! Hash-based message authentication codes (RFC 2104).
+
+use crate::{util::memcpy, Sha224, Sha256, Sha384, Sha512, Sha512_224, Sha512_256};
+
+/// HMAC-SHA256 with the key schedule precomputed.
+///
+/// Constructing an instance absorbs the padded key into both the inner and
+/// outer hash contexts. Computing a tag then only needs to hash the message
+/// and a single outer block, rather than re-hashing the key blocks on every
+/// call. Constructing the instance in a `const` moves that work to compile
+/// time.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::hmac::PrecomputedHmacSha256;
+/// const KEY: PrecomputedHmacSha256 = PrecomputedHmacSha256::new(b"key");
+///
+/// let inner_digest = KEY
+///     .inner()
+///     .update(b"The quick brown fox ")
+///     .update(b"jumps over the lazy dog")
+///     .finalize();
+/// let tag = KEY.finalize(&inner_digest);
+///
+/// assert_eq!(
+///     hex::encode(&tag[..]),
+///     "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+/// );
+/// ```
+#[derive(Clone)]
+pub struct PrecomputedHmacSha256 {
+    inner: Sha256,
+    outer: Sha256,
+}
+
+impl PrecomputedHmacSha256 {
+    /// The size of the authentication tag.
+    pub const DIGEST_SIZE: usize = Sha256::DIGEST_SIZE;
+
+    /// Precompute the inner and outer hash contexts for `key`.
+    ///
+    /// Keys longer than the block size are hashed first, as specified by RFC
+    /// 2104.
+    pub const fn new(key: &[u8]) -> Self {
+        let hmac = HmacSha256::new(key);
+        Self {
+            inner: hmac.inner,
+            outer: hmac.outer,
+        }
+    }
+
+    /// Returns the inner hash context, with the key already absorbed.
+    ///
+    /// The message should be added to this context, and its digest passed to
+    /// [`finalize`](Self::finalize).
+    #[must_use]
+    pub const fn inner(&self) -> Sha256 {
+        Sha256 {
+            inner: self.inner.inner,
+        }
+    }
+
+    /// Compute the authentication tag from the digest of the inner hash
+    /// context.
+    #[must_use]
+    pub const fn finalize(
+        &self,
+        inner_digest: &[u8; Self::DIGEST_SIZE],
+    ) -> [u8; Self::DIGEST_SIZE] {
+        Sha256 {
+            inner: self.outer.inner,
+        }
+        .update(inner_digest)
+        .finalize()
+    }
+
+    /// Compute the authentication tag for `input`.
+    #[must_use]
+    pub const fn mac(&self, input: &[u8]) -> [u8; Self::DIGEST_SIZE] {
+        self.finalize(&self.inner().update(input).finalize())
+    }
+}
+
+macro_rules! hmac {
+    ($(#[$doc:meta])* $name:ident, $hash:ty) => {
+        $(#[$doc])*
+        #[derive(Clone)]
+        pub struct $name {
+            inner: $hash,
+            outer: $hash,
+        }
+
+        impl $name {
+            /// The internal block size of the hash function.
+            pub const BLOCK_SIZE: usize = <$hash>::BLOCK_SIZE;
+            /// The size of the authentication tag.
+            pub const DIGEST_SIZE: usize = <$hash>::DIGEST_SIZE;
+
+            /// Construct a new instance keyed with `key`.
+            ///
+            /// Keys longer than the block size are hashed first, as specified by
+            /// RFC 2104.
+            pub const fn new(key: &[u8]) -> Self {
+                let mut block = [0; Self::BLOCK_SIZE];
+                if key.len() > Self::BLOCK_SIZE {
+                    let digest = <$hash>::new().update(key).finalize();
+                    memcpy(&mut block, 0, &digest, 0, digest.len());
+                } else {
+                    memcpy(&mut block, 0, key, 0, key.len());
+                }
+
+                let mut ipad = [0x36; Self::BLOCK_SIZE];
+                let mut opad = [0x5c; Self::BLOCK_SIZE];
+                let mut i = 0;
+                while i < Self::BLOCK_SIZE {
+                    ipad[i] ^= block[i];
+                    opad[i] ^= block[i];
+                    i += 1;
+                }
+
+                Self {
+                    inner: <$hash>::new().update(&ipad),
+                    outer: <$hash>::new().update(&opad),
+                }
+            }
+
+            /// Add input data to the MAC context.
+            #[must_use]
+            pub const fn update(mut self, input: &[u8]) -> Self {
+                self.inner = self.inner.update(input);
+                self
+            }
+
+            /// Finalize the context and compute the authentication tag.
+            #[must_use]
+            pub const fn finalize(self) -> [u8; Self::DIGEST_SIZE] {
+                self.outer.update(&self.inner.finalize()).finalize()
+            }
+        }
+    };
+}
+
+hmac!(
+    /// The HMAC-SHA224 message authentication code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sha2_const::hmac::HmacSha224;
+    /// const TAG: [u8; 28] = HmacSha224::new(b"key")
+    ///     .update(b"The quick brown fox ")
+    ///     .update(b"jumps over the lazy dog")
+    ///     .finalize();
+    ///
+    /// assert_eq!(
+    ///     hex::encode(&TAG[..]),
+    ///     "88ff8b54675d39b8f72322e65ff945c52d96379988ada25639747e69"
+    /// );
+    /// ```
+    HmacSha224,
+    Sha224
+);
+
+hmac!(
+    /// The HMAC-SHA256 message authentication code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sha2_const::hmac::HmacSha256;
+    /// const TAG: [u8; 32] = HmacSha256::new(b"key")
+    ///     .update(b"The quick brown fox ")
+    ///     .update(b"jumps over the lazy dog")
+    ///     .finalize();
+    ///
+    /// assert_eq!(
+    ///     hex::encode(&TAG[..]),
+    ///     "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+    /// );
+    /// ```
+    HmacSha256,
+    Sha256
+);
+
+hmac!(
+    /// The HMAC-SHA384 message authentication code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sha2_const::hmac::HmacSha384;
+    /// const TAG: [u8; 48] = HmacSha384::new(b"key")
+    ///     .update(b"The quick brown fox ")
+    ///     .update(b"jumps over the lazy dog")
+    ///     .finalize();
+    ///
+    /// assert_eq!(
+    ///     hex::encode(&TAG[..]),
+    ///     concat!(
+    ///         "d7f4727e2c0b39ae0f1e40cc96f60242d5b7801841cea6fc592c5d3e1ae50700",
+    ///         "582a96cf35e1e554995fe4e03381c237"
+    ///     )
+    /// );
+    /// ```
+    HmacSha384,
+    Sha384
+);
+
+hmac!(
+    /// The HMAC-SHA512 message authentication code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sha2_const::hmac::HmacSha512;
+    /// const TAG: [u8; 64] = HmacSha512::new(b"key")
+    ///     .update(b"The quick brown fox ")
+    ///     .update(b"jumps over the lazy dog")
+    ///     .finalize();
+    ///
+    /// assert_eq!(
+    ///     hex::encode(&TAG[..]),
+    ///     concat!(
+    ///         "b42af09057bac1e2d41708e48a902e09b5ff7f12ab428a4fe86653c73dd248fb",
+    ///         "82f948a549f7b791a5b41915ee4d1ec3935357e4e2317250d0372afa2ebeeb3a"
+    ///     )
+    /// );
+    /// ```
+    HmacSha512,
+    Sha512
+);
+
+hmac!(
+    /// The HMAC-SHA512/224 message authentication code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sha2_const::hmac::HmacSha512_224;
+    /// const TAG: [u8; 28] = HmacSha512_224::new(b"key")
+    ///     .update(b"The quick brown fox ")
+    ///     .update(b"jumps over the lazy dog")
+    ///     .finalize();
+    ///
+    /// assert_eq!(
+    ///     hex::encode(&TAG[..]),
+    ///     "a1afb4f708cb63570639195121785ada3dc615989cc3c73f38e306a3"
+    /// );
+    /// ```
+    HmacSha512_224,
+    Sha512_224
+);
+
+hmac!(
+    /// The HMAC-SHA512/256 message authentication code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sha2_const::hmac::HmacSha512_256;
+    /// const TAG: [u8; 32] = HmacSha512_256::new(b"key")
+    ///     .update(b"The quick brown fox ")
+    ///     .update(b"jumps over the lazy dog")
+    ///     .finalize();
+    ///
+    /// assert_eq!(
+    ///     hex::encode(&TAG[..]),
+    ///     "7fb65e03577da9151a1016e9c2e514d4d48842857f13927f348588173dca6d89"
+    /// );
+    /// ```
+    HmacSha512_256,
+    Sha512_256
+);
+
+/// Instantiates HMAC_DRBG with HMAC-SHA256 (NIST SP 800-90A, section 10.1.2.3)
+/// and returns the resulting value of `V`.
+///
+/// The seed material is `entropy || personalization`. As in the standard, the
+/// instantiation starts with `Key = 0x00 00...00` and `V = 0x01 01...01`, then
+/// applies the HMAC_DRBG update function to the seed material. A nonce, if
+/// any, should be appended to `entropy`.
+///
+/// Only the instantiate step is implemented, so the returned value is suitable
+/// as a seed for a deterministic generator but is not itself the output of
+/// HMAC_DRBG.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::hmac::drbg_seed;
+/// const SEED: [u8; 32] = drbg_seed(b"test entropy 0123456789abcdef", b"sha2-const tests");
+///
+/// assert_eq!(
+///     hex::encode(&SEED[..]),
+///     "4392e3b2635080b598a29fa55e1d97e464cf8ea6f36a639556b690883bd97bc5"
+/// );
+/// ```
+#[must_use]
+pub const fn drbg_seed(entropy: &[u8], personalization: &[u8]) -> [u8; 32] {
+    let key = [0x00; 32];
+    let v = [0x01; 32];
+
+    let (key, v) = drbg_update(&key, &v, 0x00, entropy, personalization);
+    if entropy.is_empty() && personalization.is_empty() {
+        return v;
+    }
+    let (_, v) = drbg_update(&key, &v, 0x01, entropy, personalization);
+    v
+}
+
+/// Performs one round of the HMAC_DRBG update function, returning the new
+/// `(Key, V)`.
+const fn drbg_update(
+    key: &[u8; 32],
+    v: &[u8; 32],
+    round: u8,
+    entropy: &[u8],
+    personalization: &[u8],
+) -> ([u8; 32], [u8; 32]) {
+    let hmac = PrecomputedHmacSha256::new(key);
+    let key = hmac.finalize(
+        &hmac
+            .inner()
+            .update(v)
+            .update(&[round])
+            .update(entropy)
+            .update(personalization)
+            .finalize(),
+    );
+    let v = PrecomputedHmacSha256::new(&key).mac(v);
+    (key, v)
+}
@@ -0,0 +1,95 @@
+//! Lightweight keyed hash constructions.
+//!
+//! These constructions are **not** HMAC. They exist for interoperability with
+//! protocols that specify them; new designs should prefer HMAC.
+
+use crate::{util::ct_eq, Sha256};
+
+/// Computes `SHA-256(len(key) || key || msg)`, where `len(key)` is the length
+/// of the key in bytes, encoded as a 64-bit big endian integer.
+///
+/// Prefixing the key length removes the ambiguity between the end of the key
+/// and the start of the message, so `("ab", "c")` and `("a", "bc")` produce
+/// different outputs.
+///
+/// # Security
+///
+/// Unlike HMAC, this construction inherits the length extension property of
+/// SHA-256: anyone who knows the output for `msg` can compute the output for
+/// `msg || padding || suffix` without knowing the key. It is only suitable
+/// when the message length is fixed or otherwise authenticated by the
+/// protocol.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::keyed::keyed_hash;
+/// const TAG: [u8; 32] = keyed_hash(
+///     b"secret key",
+///     b"The quick brown fox jumps over the lazy dog",
+/// );
+///
+/// assert_eq!(
+///     hex::encode(&TAG[..]),
+///     "2d32488e47f5ad4249057a99032cf8963e0c8d04b86d3fd49904fdb9c28f6368"
+/// );
+/// ```
+#[must_use]
+pub const fn keyed_hash(key: &[u8], msg: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .update(&(key.len() as u64).to_be_bytes())
+        .update(key)
+        .update(msg)
+        .finalize()
+}
+
+/// Computes `SHA-256(msg || key)`.
+///
+/// This secret-suffix construction is used by some legacy protocols, and is
+/// provided to reproduce them exactly.
+///
+/// # Security
+///
+/// The key is only absorbed after the whole message, so the output depends on
+/// the message solely through the intermediate hash state. Anyone who finds
+/// two messages that collide in that state (which needs no knowledge of the
+/// key) can substitute one for the other under every key. HMAC
+/// does not have this weakness, and should be used instead for new designs.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::keyed::suffix_mac;
+/// const TAG: [u8; 32] = suffix_mac(
+///     b"secret key",
+///     b"The quick brown fox jumps over the lazy dog",
+/// );
+///
+/// assert_eq!(
+///     hex::encode(&TAG[..]),
+///     "ef9e10096a2b7b74f897449b4b2dcfcf0fd8be1bdd1825b193f14875a95001f4"
+/// );
+/// ```
+#[must_use]
+pub const fn suffix_mac(key: &[u8], msg: &[u8]) -> [u8; 32] {
+    Sha256::new().update(msg).update(key).finalize()
+}
+
+/// Returns `true` if `tag` is the [`suffix_mac`] of `msg` under `key`.
+///
+/// The comparison is constant-time: it does not stop at the first byte that
+/// differs.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::keyed::{suffix_mac, verify_suffix_mac};
+/// const TAG: [u8; 32] = suffix_mac(b"secret key", b"message");
+///
+/// assert!(verify_suffix_mac(b"secret key", b"message", &TAG));
+/// assert!(!verify_suffix_mac(b"other key", b"message", &TAG));
+/// ```
+#[must_use]
+pub const fn verify_suffix_mac(key: &[u8], msg: &[u8], tag: &[u8; 32]) -> bool {
+    ct_eq(&suffix_mac(key, msg), tag)
+}
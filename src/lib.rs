@@ -26,12 +26,12 @@
 //! const BLOCK_HASH: [u8; 32] = Sha256::new()
 //!     .update(
 //!         &Sha256::new()
-//!             .update(&VERSION.to_le_bytes())
+//!             .update_u32_le(VERSION)
 //!             .update(&HASH_PREV_BLOCK)
 //!             .update(&HASH_MERKLE_ROOT)
-//!             .update(&TIME.to_le_bytes())
-//!             .update(&BITS.to_le_bytes())
-//!             .update(&NONCE.to_le_bytes())
+//!             .update_u32_le(TIME)
+//!             .update_u32_le(BITS)
+//!             .update_u32_le(NONCE)
 //!             .finalize(),
 //!     )
 //!     .finalize();
@@ -41,22 +41,66 @@
 //!     "6fe28c0ab6f1b372c1a6a246ae63f74f931e8365e15a089c68d6190000000000"
 //! );
 //! ```
-#![feature(const_mut_refs)]
+//!
+//! # Features
+//!
+//! - `alloc`: adds methods that return heap-allocated values, such as
+//!   `finalize_vec`.
+//! - `digest-traits`: implements the traits of the [`digest`][digest-crate]
+//!   crate for the hash functions, for use at run time.
+//! - `serde`: implements `Serialize` and `Deserialize` for [`Digest`].
+//! - `std`: implements `std::io::Write` for the hash functions, so they can be
+//!   used with `std::io::copy`, and adds `update_reader`. This implies `alloc`.
+//!
+//! [digest-crate]: https://crates.io/crates/digest
 #![no_std]
 
-mod constants;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod chain;
+pub mod check;
+pub mod combine;
+pub mod constants;
+pub mod derive;
+pub mod digest;
+pub mod git;
+pub mod hkdf;
+pub mod hmac;
+pub mod keyed;
+pub mod merkle;
+pub mod mgf;
+pub mod pbkdf2;
+pub mod prf;
+pub mod region;
 mod sha;
+pub mod structured;
+#[cfg(feature = "digest-traits")]
+mod traits;
 mod util;
 
-use constants::{H224, H256, H384, H512, H512_224, H512_256};
-use util::memcpy;
+use self::digest::Digest;
+use constants::{
+    DIGEST_INFO_224, DIGEST_INFO_256, DIGEST_INFO_384, DIGEST_INFO_512, DIGEST_INFO_512_224,
+    DIGEST_INFO_512_256, H224, H256, H384, H512, H512_224, H512_256,
+};
+use core::{fmt, hash::Hasher, mem};
+use util::{memcpy, panic_at_index, u64_to_decimal};
+
+pub use util::{
+    ct_eq, from_hex, pem_body_bytes, to_base32_crockford, to_base64, to_base64_url, to_hex,
+    to_hex_upper,
+};
 
 macro_rules! sha {
     (
         $(#[$doc:meta])* $name:ident,
         $size:literal,
         $inner:ty,
-        $iv:ident
+        $iv:ident,
+        $digest_info:ident
     ) => {
         $(#[$doc])*
         #[derive(Clone)]
@@ -67,6 +111,9 @@ macro_rules! sha {
         impl $name {
             /// The internal block size of the hash function.
             pub const BLOCK_SIZE: usize = <$inner>::BLOCK_SIZE;
+            /// The DER encoding of the ASN.1 `DigestInfo` structure that precedes
+            /// the digest in PKCS #1 v1.5 signatures (RFC 8017, section 9.2).
+            pub const DIGEST_INFO_PREFIX: &'static [u8] = $digest_info;
             /// The digest size of the hash function.
             pub const DIGEST_SIZE: usize = $size;
 
@@ -84,6 +131,395 @@ macro_rules! sha {
                 self
             }
 
+            /// Add the first `bit_len` bits of `input` to the hash context.
+            ///
+            /// SHA-2 is defined on messages of any number of bits, as exercised by
+            /// the bit-oriented NIST test vectors. If `bit_len` is not a multiple
+            /// of 8, the final bits are the most significant bits of
+            /// `input[bit_len / 8]`, and this must be the last input data added.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `input` is shorter than `bit_len` bits. Adding more input
+            /// data after a partial byte also panics.
+            #[must_use]
+            pub const fn update_bits(mut self, input: &[u8], bit_len: usize) -> Self {
+                self.inner.update_bits(input, bit_len);
+                self
+            }
+
+            /// Add each of `parts` to the hash context, in order.
+            ///
+            /// This is equivalent to calling [`update`](Self::update) for each part.
+            #[must_use]
+            pub const fn update_all(mut self, parts: &[&[u8]]) -> Self {
+                let mut i = 0;
+                while i < parts.len() {
+                    self.inner.update(parts[i]);
+                    i += 1;
+                }
+                self
+            }
+
+            /// Add all of the input data from `reader` to the hash context.
+            ///
+            /// This is not a `const fn`. The data is read in chunks of
+            /// [`BLOCK_SIZE`](Self::BLOCK_SIZE) bytes until the end of the input,
+            /// so it doesn't need to fit in memory. Interrupted reads are retried.
+            ///
+            /// # Errors
+            ///
+            /// Returns any other error from `reader`. The input data read before
+            /// the error is lost along with the hash context.
+            #[cfg(feature = "std")]
+            pub fn update_reader<R: std::io::Read>(
+                mut self,
+                mut reader: R,
+            ) -> std::io::Result<Self> {
+                let mut buffer = [0; Self::BLOCK_SIZE];
+                loop {
+                    match reader.read(&mut buffer) {
+                        Ok(0) => return Ok(self),
+                        Ok(len) => self.inner.update_range(&buffer, 0, len),
+                        Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+            }
+
+            /// Add the length of `input` in bits, encoded as a 64-bit big endian
+            /// integer, followed by `input` to the hash context.
+            ///
+            /// Note that the length is in bits, not bytes, matching the encoding of
+            /// the length field in SHA-2 padding.
+            #[must_use]
+            pub const fn update_with_len_prefix(mut self, input: &[u8]) -> Self {
+                self.inner.update(&((input.len() as u64) * 8).to_be_bytes());
+                self.inner.update(input);
+                self
+            }
+
+            /// Add the byte `n` to the hash context.
+            #[must_use]
+            pub const fn update_u8(mut self, n: u8) -> Self {
+                self.inner.update_byte(n);
+                self
+            }
+
+            /// Add a single byte to the hash context.
+            ///
+            /// This is the same as [`update_u8`](Self::update_u8), and is cheaper
+            /// than [`update`](Self::update) with a one-byte slice when input data
+            /// is generated a byte at a time.
+            #[must_use]
+            pub const fn update_byte(mut self, b: u8) -> Self {
+                self.inner.update_byte(b);
+                self
+            }
+
+            /// Add `n`, encoded as a 16-bit big endian integer, to the hash context.
+            #[must_use]
+            pub const fn update_u16_be(mut self, n: u16) -> Self {
+                self.inner.update(&n.to_be_bytes());
+                self
+            }
+
+            /// Add `n`, encoded as a 16-bit little endian integer, to the hash
+            /// context.
+            #[must_use]
+            pub const fn update_u16_le(mut self, n: u16) -> Self {
+                self.inner.update(&n.to_le_bytes());
+                self
+            }
+
+            /// Add `n`, encoded as a 32-bit big endian integer, to the hash context.
+            #[must_use]
+            pub const fn update_u32_be(mut self, n: u32) -> Self {
+                self.inner.update(&n.to_be_bytes());
+                self
+            }
+
+            /// Add `n`, encoded as a 32-bit little endian integer, to the hash
+            /// context.
+            #[must_use]
+            pub const fn update_u32_le(mut self, n: u32) -> Self {
+                self.inner.update(&n.to_le_bytes());
+                self
+            }
+
+            /// Add `n`, encoded as a 64-bit big endian integer, to the hash context.
+            #[must_use]
+            pub const fn update_u64_be(mut self, n: u64) -> Self {
+                self.inner.update(&n.to_be_bytes());
+                self
+            }
+
+            /// Add `n`, encoded as a 64-bit little endian integer, to the hash
+            /// context.
+            #[must_use]
+            pub const fn update_u64_le(mut self, n: u64) -> Self {
+                self.inner.update(&n.to_le_bytes());
+                self
+            }
+
+            /// Add `n`, encoded as a 128-bit big endian integer, to the hash context.
+            #[must_use]
+            pub const fn update_u128_be(mut self, n: u128) -> Self {
+                self.inner.update(&n.to_be_bytes());
+                self
+            }
+
+            /// Add `n`, encoded as a 128-bit little endian integer, to the hash
+            /// context.
+            #[must_use]
+            pub const fn update_u128_le(mut self, n: u128) -> Self {
+                self.inner.update(&n.to_le_bytes());
+                self
+            }
+
+            /// Add `n`, encoded as an 8-bit two's complement integer, to the hash
+            /// context.
+            #[must_use]
+            pub const fn update_i8(mut self, n: i8) -> Self {
+                self.inner.update(&n.to_be_bytes());
+                self
+            }
+
+            /// Add `n`, encoded as a 16-bit two's complement big endian integer, to
+            /// the hash context.
+            #[must_use]
+            pub const fn update_i16_be(mut self, n: i16) -> Self {
+                self.inner.update(&n.to_be_bytes());
+                self
+            }
+
+            /// Add `n`, encoded as a 16-bit two's complement little endian integer,
+            /// to the hash context.
+            #[must_use]
+            pub const fn update_i16_le(mut self, n: i16) -> Self {
+                self.inner.update(&n.to_le_bytes());
+                self
+            }
+
+            /// Add `n`, encoded as a 32-bit two's complement big endian integer, to
+            /// the hash context.
+            ///
+            /// This is suitable for fixed-point values such as Q16.16 numbers
+            /// stored in an `i32`, which hash deterministically unlike floating
+            /// point values.
+            #[must_use]
+            pub const fn update_i32_be(mut self, n: i32) -> Self {
+                self.inner.update(&n.to_be_bytes());
+                self
+            }
+
+            /// Add `n`, encoded as a 32-bit two's complement little endian integer,
+            /// to the hash context.
+            #[must_use]
+            pub const fn update_i32_le(mut self, n: i32) -> Self {
+                self.inner.update(&n.to_le_bytes());
+                self
+            }
+
+            /// Add `n`, encoded as a 64-bit two's complement big endian integer, to
+            /// the hash context.
+            #[must_use]
+            pub const fn update_i64_be(mut self, n: i64) -> Self {
+                self.inner.update(&n.to_be_bytes());
+                self
+            }
+
+            /// Add `n`, encoded as a 64-bit two's complement little endian integer,
+            /// to the hash context.
+            #[must_use]
+            pub const fn update_i64_le(mut self, n: i64) -> Self {
+                self.inner.update(&n.to_le_bytes());
+                self
+            }
+
+            /// Add a NUL-terminated C string to the hash context.
+            ///
+            /// The bytes of `bytes` up to, but not including, the first `0x00` byte
+            /// are added. If there is no `0x00` byte, all of `bytes` is added.
+            #[must_use]
+            pub const fn update_cstr(mut self, bytes: &[u8]) -> Self {
+                let mut len = 0;
+                while len < bytes.len() && bytes[len] != 0 {
+                    len += 1;
+                }
+                self.inner.update_range(bytes, 0, len);
+                self
+            }
+
+            /// Add input data to the hash context, with ASCII uppercase letters
+            /// converted to lowercase.
+            ///
+            /// Only the bytes `A` to `Z` are changed; all other bytes, including
+            /// non-ASCII bytes, are added unchanged.
+            #[must_use]
+            pub const fn update_ascii_lower(mut self, input: &[u8]) -> Self {
+                let mut chunk = [0; Self::BLOCK_SIZE];
+                let mut i = 0;
+                while i < input.len() {
+                    let len = if input.len() - i < Self::BLOCK_SIZE {
+                        input.len() - i
+                    } else {
+                        Self::BLOCK_SIZE
+                    };
+                    let mut j = 0;
+                    while j < len {
+                        chunk[j] = input[i + j].to_ascii_lowercase();
+                        j += 1;
+                    }
+                    self.inner.update_range(&chunk, 0, len);
+                    i += len;
+                }
+                self
+            }
+
+            /// Add input data to the hash context, without any trailing whitespace.
+            ///
+            /// The whitespace bytes are space (`0x20`), tab (`0x09`), line feed
+            /// (`0x0a`) and carriage return (`0x0d`). Leading and interior
+            /// whitespace is added unchanged.
+            #[must_use]
+            pub const fn update_rstrip(mut self, input: &[u8]) -> Self {
+                let mut len = input.len();
+                while len > 0 && matches!(input[len - 1], b' ' | b'\t' | b'\n' | b'\r') {
+                    len -= 1;
+                }
+                self.inner.update_range(input, 0, len);
+                self
+            }
+
+            /// Add input data to the hash context, after checking that it is
+            /// printable ASCII.
+            ///
+            /// Printable ASCII is the bytes `0x20` (space) to `0x7e` (`~`). When
+            /// evaluated in a `const` item, a violation is reported as a compile
+            /// error giving the index of the first offending byte.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any byte of `input` is not printable ASCII.
+            #[must_use]
+            pub const fn update_printable_ascii(mut self, input: &[u8]) -> Self {
+                let mut i = 0;
+                while i < input.len() {
+                    if input[i] < 0x20 || input[i] > 0x7e {
+                        panic_at_index("non-printable byte at index ", i);
+                    }
+                    i += 1;
+                }
+                self.inner.update(input);
+                self
+            }
+
+            /// Add the UTF-8 bytes of `s` to the hash context, after checking that it
+            /// is ASCII.
+            ///
+            /// Unicode normalization is not available in `const fn`, but it never
+            /// changes ASCII strings, so restricting input to ASCII ensures that
+            /// equivalent strings hash the same. When evaluated in a `const` item,
+            /// a violation is reported as a compile error giving the byte index of
+            /// the first non-ASCII character.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `s` contains a non-ASCII character.
+            #[must_use]
+            pub const fn update_str_ascii_only(mut self, s: &str) -> Self {
+                let bytes = s.as_bytes();
+                let mut i = 0;
+                while i < bytes.len() {
+                    if !bytes[i].is_ascii() {
+                        panic_at_index("non-ASCII byte at index ", i);
+                    }
+                    i += 1;
+                }
+                self.inner.update(bytes);
+                self
+            }
+
+            /// Add input data to the hash context, followed by copies of `pad_byte`
+            /// until the length of the input is a multiple of `pad_to`.
+            ///
+            /// No padding is added if the length of `input` is already a multiple of
+            /// `pad_to`. This padding is applied to `input` alone, independently
+            /// of any data previously added, and is unrelated to the padding
+            /// applied internally by the hash function.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `pad_to` is zero.
+            #[must_use]
+            pub const fn update_padded(
+                mut self,
+                input: &[u8],
+                pad_to: usize,
+                pad_byte: u8,
+            ) -> Self {
+                assert!(pad_to != 0);
+                self.inner.update(input);
+                self.inner
+                    .update_repeat(pad_byte, (pad_to - input.len() % pad_to) % pad_to);
+                self
+            }
+
+            /// Reset the context to its initial state, discarding all input data.
+            ///
+            /// An instance constructed by `from_midstate` is reset to that
+            /// midstate.
+            pub const fn reset(&mut self) {
+                self.inner.reset();
+            }
+
+            /// Returns the number of bytes of input data added so far.
+            ///
+            /// Lengths that do not fit in a `u64` are truncated. A partial byte
+            /// added with [`update_bits`](Self::update_bits) is not counted.
+            pub const fn byte_len(&self) -> u64 {
+                self.inner.byte_len() as u64
+            }
+
+            /// Returns the number of bytes of input data added so far.
+            ///
+            /// This is the same as [`byte_len`](Self::byte_len), and is provided
+            /// alongside [`is_empty`](Self::is_empty) for consistency with
+            /// collections. Lengths that do not fit in a `u64` are truncated.
+            pub const fn len(&self) -> u64 {
+                self.byte_len()
+            }
+
+            /// Returns `true` if no input data has been added.
+            pub const fn is_empty(&self) -> bool {
+                self.inner.bit_len() == 0
+            }
+
+            /// Returns the length in bytes of a message of `message_len` bytes
+            /// after padding.
+            ///
+            /// The padding is a `0x80` byte, then zero bytes, then the length of
+            /// the message in bits, filling the last block. This is the number of
+            /// bytes that are compressed when the message is hashed.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the padded length does not fit in a `u64`.
+            #[must_use]
+            pub const fn padded_len(message_len: u64) -> u64 {
+                <$inner>::padded_len(message_len)
+            }
+
+            /// Returns the number of bytes of input data buffered awaiting a full
+            /// block.
+            ///
+            /// This is always less than [`BLOCK_SIZE`](Self::BLOCK_SIZE); complete
+            /// blocks are compressed as soon as they are available.
+            pub const fn buffered_bytes(&self) -> usize {
+                self.inner.buffered_bytes()
+            }
+
             /// Finalize the context and compute the digest.
             #[must_use]
             pub const fn finalize(self) -> [u8; Self::DIGEST_SIZE] {
@@ -92,6 +528,222 @@ macro_rules! sha {
                 memcpy(&mut truncated, 0, &digest, 0, Self::DIGEST_SIZE);
                 truncated
             }
+
+            /// Finalize the context and return the first `M` bytes of the digest.
+            ///
+            /// This is for protocols that specify a nonstandard truncation. Note
+            /// that this is not the same as the SHA-512/t hash functions, which
+            /// also use a different initialization vector.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `M` is greater than [`DIGEST_SIZE`](Self::DIGEST_SIZE).
+            #[must_use]
+            pub const fn finalize_truncated<const M: usize>(self) -> [u8; M] {
+                assert!(M <= Self::DIGEST_SIZE);
+                let digest = self.inner.finalize();
+                let mut truncated = [0; M];
+                memcpy(&mut truncated, 0, &digest, 0, M);
+                truncated
+            }
+
+            /// Finalize the context and return the digest in a [`Vec`].
+            ///
+            /// This is not a `const fn`. It is useful for storing digests of
+            /// different sizes together, for example behind a trait object.
+            ///
+            /// [`Vec`]: alloc::vec::Vec
+            #[cfg(feature = "alloc")]
+            #[must_use]
+            pub fn finalize_vec(self) -> alloc::vec::Vec<u8> {
+                self.finalize().to_vec()
+            }
+
+            /// Compute the digest of the input data added so far, then reset the
+            /// context to its initial state.
+            #[must_use]
+            pub const fn finalize_reset(&mut self) -> [u8; Self::DIGEST_SIZE] {
+                let digest = Self { inner: self.inner }.finalize();
+                self.inner.reset();
+                digest
+            }
+
+            /// Finalize the context and compute the digest, wrapped in a [`Digest`]
+            /// for formatting.
+            #[must_use]
+            pub const fn finalize_digest(self) -> Digest<$size> {
+                Digest::new(self.finalize())
+            }
+
+            /// Finalize the context and compute the digest, encoded as lowercase
+            /// hex.
+            #[must_use]
+            pub const fn finalize_hex(self) -> [u8; Self::DIGEST_SIZE * 2] {
+                to_hex(&self.finalize())
+            }
+
+            /// Finalize the context and compute the digest, encoded as uppercase
+            /// hex.
+            #[must_use]
+            pub const fn finalize_hex_upper(self) -> [u8; Self::DIGEST_SIZE * 2] {
+                to_hex_upper(&self.finalize())
+            }
+
+            /// Finalize the context and compute the digest, encoded as standard
+            /// base64 with padding.
+            #[must_use]
+            pub const fn finalize_base64(self) -> [u8; Self::DIGEST_SIZE.div_ceil(3) * 4] {
+                to_base64(&self.finalize())
+            }
+
+            /// Finalize the context and compute the digest, encoded as URL-safe
+            /// base64 without padding.
+            #[must_use]
+            pub const fn finalize_base64_url(self) -> [u8; (Self::DIGEST_SIZE * 8).div_ceil(6)] {
+                to_base64_url(&self.finalize())
+            }
+
+            /// Finalize the context and return `true` if the digest is equal to
+            /// `expected`.
+            ///
+            /// This is primarily a build-time integrity guard: in
+            /// `const _: () = assert!(...)`, a mismatch between embedded data and
+            /// its pinned digest fails the build. The comparison is also
+            /// branch-free and constant-time, as with [`ct_eq`], so it is safe to
+            /// use at run time.
+            #[must_use]
+            pub const fn verify(self, expected: &[u8; Self::DIGEST_SIZE]) -> bool {
+                ct_eq(&self.finalize(), expected)
+            }
+
+            /// Finalize the context and write the digest into `out` (starting at
+            /// `offset`).
+            ///
+            /// # Panics
+            ///
+            /// Panics if `out` is too short to hold the digest at `offset`.
+            pub const fn finalize_into(self, out: &mut [u8], offset: usize) {
+                assert!(offset <= out.len() && Self::DIGEST_SIZE <= out.len() - offset);
+                let digest = self.inner.finalize();
+                memcpy(out, offset, &digest, 0, Self::DIGEST_SIZE);
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        /// Only the number of bytes added is shown, so that the input data
+        /// buffered in the context is not leaked into logs.
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("byte_len", &self.byte_len())
+                    .finish_non_exhaustive()
+            }
+        }
+
+        /// Allows input data to be written to the hash context, for example with
+        /// [`std::io::copy`]. Writes always succeed and consume the whole
+        /// buffer.
+        #[cfg(feature = "std")]
+        impl std::io::Write for $name {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                *self = mem::take(self).update(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        /// Allows the hash function to be used with hash maps, for example
+        /// through [`BuildHasherDefault`](core::hash::BuildHasherDefault).
+        ///
+        /// The hash is the first eight bytes of the digest, interpreted as a
+        /// 64-bit big endian integer. As required by [`Hasher`], `finish` does
+        /// not consume the hash context: it finalizes a copy, so more data can
+        /// be written afterwards.
+        impl Hasher for $name {
+            fn write(&mut self, bytes: &[u8]) {
+                *self = mem::take(self).update(bytes);
+            }
+
+            fn finish(&self) -> u64 {
+                let digest = self.clone().finalize();
+                u64::from_be_bytes([
+                    digest[0], digest[1], digest[2], digest[3], digest[4], digest[5], digest[6],
+                    digest[7],
+                ])
+            }
+        }
+    };
+}
+
+macro_rules! words {
+    ($name:ident, $word:ty, $length:ty) => {
+        impl $name {
+            /// Construct an instance that starts from a custom initialization
+            /// vector instead of the standard one.
+            ///
+            /// The standard initialization vectors are in [`constants`].
+            ///
+            /// # Security
+            ///
+            /// The result is not the standard hash function, and none of its
+            /// security properties carry over to an arbitrary initialization
+            /// vector. This is intended for building and studying nonstandard
+            /// constructions only.
+            pub const fn new_with_iv(iv: [$word; 8]) -> Self {
+                Self {
+                    inner: <sha::$name>::new(iv),
+                }
+            }
+
+            /// Construct an instance that resumes hashing from a midstate.
+            ///
+            /// `state` and `length` are the state words and the length of the
+            /// input data in bits, as returned by
+            /// [`into_midstate`](Self::into_midstate).
+            ///
+            /// # Panics
+            ///
+            /// Panics if `length` is not a whole number of blocks.
+            pub const fn from_midstate(state: [$word; 8], length: $length) -> Self {
+                Self {
+                    inner: <sha::$name>::from_midstate(state, length),
+                }
+            }
+
+            /// Returns the midstate: the state words and the length of the input
+            /// data in bits.
+            ///
+            /// The midstate only captures complete blocks, so the input data added
+            /// so far must be a whole number of blocks. Hashing can be resumed
+            /// with [`from_midstate`](Self::from_midstate).
+            ///
+            /// # Panics
+            ///
+            /// Panics if the length of the input data is not a multiple of
+            /// [`BLOCK_SIZE`](Self::BLOCK_SIZE).
+            #[must_use]
+            pub const fn into_midstate(self) -> ([$word; 8], $length) {
+                self.inner.into_midstate()
+            }
+
+            /// Finalize the context and return the digest as state words.
+            ///
+            /// The digest returned by [`finalize`](Self::finalize) is the big
+            /// endian serialization of these words. This is only provided for the
+            /// untruncated hash functions, since the truncated variants share
+            /// these state words but discard some of them from the digest.
+            #[must_use]
+            pub const fn finalize_words(self) -> [$word; 8] {
+                self.inner.finalize_state()
+            }
         }
     };
 }
@@ -119,7 +771,8 @@ sha!(
     Sha224,
     28,
     sha::Sha256,
-    H224
+    H224,
+    DIGEST_INFO_224
 );
 
 sha!(
@@ -142,7 +795,8 @@ sha!(
     Sha256,
     32,
     sha::Sha256,
-    H256
+    H256,
+    DIGEST_INFO_256
 );
 
 sha!(
@@ -171,7 +825,8 @@ sha!(
     Sha384,
     48,
     sha::Sha512,
-    H384
+    H384,
+    DIGEST_INFO_384
 );
 
 sha!(
@@ -197,7 +852,8 @@ sha!(
     Sha512,
     64,
     sha::Sha512,
-    H512
+    H512,
+    DIGEST_INFO_512
 );
 
 sha!(
@@ -223,7 +879,8 @@ sha!(
     Sha512_224,
     28,
     sha::Sha512,
-    H512_224
+    H512_224,
+    DIGEST_INFO_512_224
 );
 
 sha!(
@@ -249,5 +906,271 @@ sha!(
     Sha512_256,
     32,
     sha::Sha512,
-    H512_256
+    H512_256,
+    DIGEST_INFO_512_256
+);
+
+macro_rules! oneshot {
+    ($(#[$doc:meta])* $fn:ident, $name:ident) => {
+        $(#[$doc])*
+        #[must_use]
+        pub const fn $fn(input: &[u8]) -> [u8; $name::DIGEST_SIZE] {
+            $name::new().update(input).finalize()
+        }
+    };
+}
+
+oneshot!(
+    /// Computes the SHA-224 digest of `input`.
+    sha224,
+    Sha224
+);
+
+oneshot!(
+    /// Computes the SHA-256 digest of `input`.
+    ///
+    /// This is shorthand for `Sha256::new().update(input).finalize()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sha2_const::sha256;
+    /// const DIGEST: [u8; 32] = sha256(b"hello");
+    ///
+    /// assert_eq!(
+    ///     hex::encode(&DIGEST[..]),
+    ///     "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+    /// );
+    /// ```
+    sha256,
+    Sha256
+);
+
+oneshot!(
+    /// Computes the SHA-384 digest of `input`.
+    sha384,
+    Sha384
+);
+
+oneshot!(
+    /// Computes the SHA-512 digest of `input`.
+    sha512,
+    Sha512
 );
+
+oneshot!(
+    /// Computes the SHA-512/224 digest of `input`.
+    sha512_224,
+    Sha512_224
+);
+
+oneshot!(
+    /// Computes the SHA-512/256 digest of `input`.
+    sha512_256,
+    Sha512_256
+);
+
+/// The double SHA-256 hash function, `SHA-256(SHA-256(x))`.
+///
+/// This is the hash function used for Bitcoin block and transaction hashes.
+/// Input data is added to the first hash, and only its 32-byte digest is hashed
+/// again when the context is finalized.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{Sha256, Sha256d};
+/// const DIGEST: [u8; 32] = Sha256d::new().update(b"hel").update(b"lo").finalize();
+///
+/// assert_eq!(
+///     DIGEST,
+///     Sha256::new()
+///         .update(&Sha256::new().update(b"hello").finalize())
+///         .finalize()
+/// );
+/// assert_eq!(
+///     hex::encode(&DIGEST[..]),
+///     "9595c9df90075148eb06860365df33584b75bff782a510c6cd4883a419833d50"
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sha256d {
+    inner: Sha256,
+}
+
+impl Sha256d {
+    /// The internal block size of the hash function.
+    pub const BLOCK_SIZE: usize = Sha256::BLOCK_SIZE;
+    /// The digest size of the hash function.
+    pub const DIGEST_SIZE: usize = Sha256::DIGEST_SIZE;
+
+    /// Construct a new instance.
+    pub const fn new() -> Self {
+        Self {
+            inner: Sha256::new(),
+        }
+    }
+
+    /// Add input data to the hash context.
+    #[must_use]
+    pub const fn update(mut self, input: &[u8]) -> Self {
+        self.inner = self.inner.update(input);
+        self
+    }
+
+    /// Finalize the context and compute the digest.
+    #[must_use]
+    pub const fn finalize(self) -> [u8; Self::DIGEST_SIZE] {
+        Sha256::new().update(&self.inner.finalize()).finalize()
+    }
+
+    /// Finalize the context and compute the digest, wrapped in a [`Digest`]
+    /// for formatting.
+    #[must_use]
+    pub const fn finalize_digest(self) -> Digest<32> {
+        Digest::new(self.finalize())
+    }
+}
+
+impl Default for Sha256d {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+words!(Sha256, u32, u64);
+words!(Sha512, u64, u128);
+
+/// Generates the SHA-512/t initialization vector, as specified by FIPS 180-4
+/// section 5.3.6.
+///
+/// The initialization vector is the SHA-512 state after hashing the ASCII
+/// string `"SHA-512/t"` (with `t` in decimal), starting from the SHA-512
+/// initialization vector with every word XORed with `0xa5a5a5a5a5a5a5a5`.
+///
+/// # Panics
+///
+/// Panics if `t` is zero, 384, or not less than 512.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::sha512t_iv;
+/// const IV: [u64; 8] = sha512t_iv(256);
+///
+/// assert_eq!(IV[0], 0x22312194fc2bf72c);
+/// ```
+#[must_use]
+pub const fn sha512t_iv(t: u16) -> [u64; 8] {
+    assert!(t != 0 && t != 384 && t < 512);
+
+    let mut iv = H512;
+    let mut i = 0;
+    while i < iv.len() {
+        iv[i] ^= 0xa5a5a5a5a5a5a5a5;
+        i += 1;
+    }
+
+    let (digits, len) = u64_to_decimal(t as u64);
+
+    let mut inner = sha::Sha512::new(iv);
+    inner.update(b"SHA-512/");
+    inner.update_range(&digits, 0, len);
+    inner.finalize_state()
+}
+
+/// Computes the SHA-512/t digest of `input`, for any `T` supported by
+/// [`sha512t_iv`].
+///
+/// The initialization vector is generated at compile time by [`sha512t_iv`]
+/// and the digest is truncated to `T` bits. `N` must be the digest size in
+/// bytes, `T / 8`.
+///
+/// # Panics
+///
+/// Panics if `T` is not a multiple of 8, if `N` is not `T / 8`, or if `T` is
+/// not supported by [`sha512t_iv`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{sha512_t, Sha512_256};
+/// const DIGEST: [u8; 32] = sha512_t::<256, 32>(b"abc");
+///
+/// assert_eq!(DIGEST, Sha512_256::new().update(b"abc").finalize());
+/// ```
+#[must_use]
+pub const fn sha512_t<const T: usize, const N: usize>(input: &[u8]) -> [u8; N] {
+    assert!(T.is_multiple_of(8) && N == T / 8 && T < 512);
+
+    let mut inner = sha::Sha512::new(sha512t_iv(T as u16));
+    inner.update(input);
+    let digest = inner.finalize();
+
+    let mut truncated = [0; N];
+    memcpy(&mut truncated, 0, &digest, 0, N);
+    truncated
+}
+
+/// Applies the SHA-256 compression function to `block`, starting from the
+/// SHA-256 initialization vector, and returns the first word of the resulting
+/// state.
+///
+/// This exercises only the compression function, without any buffering or
+/// padding, which makes it a minimal target for benchmarks and a quick
+/// regression check of the core.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::compress_once;
+/// const WORD: u32 = compress_once(&[0; 64]);
+///
+/// assert_eq!(WORD, 0xda5698be);
+/// ```
+#[must_use]
+pub const fn compress_once(block: &[u8; 64]) -> u32 {
+    compress_block(H256, block)[0]
+}
+
+/// Applies the SHA-256 compression function to `block`, starting from
+/// `state`, and returns the resulting state.
+///
+/// No padding is applied. This is for building and studying constructions on
+/// top of the compression function; see [`compress_block_512`] for SHA-512.
+///
+/// # Examples
+///
+/// Hashing a single, manually padded block:
+///
+/// ```rust
+/// # use sha2_const::{compress_block, constants::H256, Sha256};
+/// const BLOCK: [u8; 64] = {
+///     let mut block = [0; 64];
+///     block[0] = b'a';
+///     block[1] = b'b';
+///     block[2] = b'c';
+///     block[3] = 0x80;
+///     block[63] = 24;
+///     block
+/// };
+/// const STATE: [u32; 8] = compress_block(H256, &BLOCK);
+///
+/// assert_eq!(STATE, Sha256::new().update(b"abc").finalize_words());
+/// ```
+#[must_use]
+pub const fn compress_block(mut state: [u32; 8], block: &[u8; 64]) -> [u32; 8] {
+    sha::Sha256::compress(&mut state, block, 0);
+    state
+}
+
+/// Applies the SHA-512 compression function to `block`, starting from
+/// `state`, and returns the resulting state.
+///
+/// No padding is applied. This is the SHA-512 equivalent of
+/// [`compress_block`].
+#[must_use]
+pub const fn compress_block_512(mut state: [u64; 8], block: &[u8; 128]) -> [u64; 8] {
+    sha::Sha512::compress(&mut state, block, 0);
+    state
+}
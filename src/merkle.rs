@@ -0,0 +1,64 @@
+//! Merkle trees of SHA-256 digests.
+
+use crate::{util::memcpy, Sha256d};
+
+/// Computes the Bitcoin-style Merkle root of `leaves`.
+///
+/// Each level of the tree is built by hashing the concatenation of adjacent
+/// pairs of nodes with double SHA-256. If a level has an odd number of nodes,
+/// the last node is paired with itself. The root of a single leaf is the leaf
+/// itself, and the root of no leaves is defined as all zeroes.
+///
+/// Bitcoin displays transaction IDs and Merkle roots with their bytes
+/// reversed, so those must be reversed before and after calling this
+/// function.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{merkle::merkle_root, Sha256};
+/// const ROOT: [u8; 32] = merkle_root([
+///     Sha256::new().update(&[0]).finalize(),
+///     Sha256::new().update(&[1]).finalize(),
+///     Sha256::new().update(&[2]).finalize(),
+/// ]);
+///
+/// assert_eq!(
+///     hex::encode(&ROOT[..]),
+///     "50fde71c451737ad83c79d791dfda614eeed7e4440971b7a92691919a06ba52b"
+/// );
+/// ```
+#[must_use]
+pub const fn merkle_root<const N: usize>(leaves: [[u8; 32]; N]) -> [u8; 32] {
+    if N == 0 {
+        return [0; 32];
+    }
+
+    // Each level is written over the start of the previous one.
+    let mut nodes = leaves;
+    let mut len = N;
+    while len > 1 {
+        let mut i = 0;
+        while i < len.div_ceil(2) {
+            let left = nodes[2 * i];
+            let right = if 2 * i + 1 < len {
+                nodes[2 * i + 1]
+            } else {
+                left
+            };
+            nodes[i] = hash_pair(&left, &right);
+            i += 1;
+        }
+        len = len.div_ceil(2);
+    }
+
+    nodes[0]
+}
+
+/// Computes `SHA-256(SHA-256(left || right))`.
+const fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut pair = [0; 64];
+    memcpy(&mut pair, 0, left, 0, left.len());
+    memcpy(&mut pair, left.len(), right, 0, right.len());
+    Sha256d::new().update(&pair).finalize()
+}
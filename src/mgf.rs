@@ -0,0 +1,49 @@
+//! Mask generation functions (RFC 8017, appendix B.2).
+
+use crate::{util::memcpy, Sha256};
+
+/// Computes MGF1 with SHA-256, generating an `L`-byte mask from `seed`.
+///
+/// The mask is the first `L` bytes of `T(0) || T(1) || ...`, where
+/// `T(i) = SHA-256(seed || i)`, with `i` encoded as a 32-bit big endian
+/// integer. This is the mask generation function used by RSA-OAEP and
+/// RSA-PSS.
+///
+/// # Panics
+///
+/// Panics if `L` is greater than 2<sup>32</sup> blocks of 32 bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::mgf::mgf1_sha256;
+/// const MASK: [u8; 20] = mgf1_sha256(b"seed");
+///
+/// assert_eq!(
+///     hex::encode(&MASK[..]),
+///     "336f28a022193939585a1b4edc989f870917f3a5"
+/// );
+/// ```
+#[must_use]
+pub const fn mgf1_sha256<const L: usize>(seed: &[u8]) -> [u8; L] {
+    assert!(L.div_ceil(Sha256::DIGEST_SIZE) as u64 <= 1 << 32);
+
+    let mut mask = [0; L];
+    let mut offset = 0;
+    let mut counter: u32 = 0;
+    while offset < L {
+        let block = Sha256::new().update(seed).update_u32_be(counter).finalize();
+
+        let n = if L - offset < block.len() {
+            L - offset
+        } else {
+            block.len()
+        };
+        memcpy(&mut mask, offset, &block, 0, n);
+        offset += n;
+        // With the maximum output length, this only wraps after the last block.
+        counter = counter.wrapping_add(1);
+    }
+
+    mask
+}
@@ -0,0 +1,69 @@
+//! Password-based key derivation function 2 (RFC 8018) with HMAC-SHA256.
+
+use crate::{hmac::PrecomputedHmacSha256, util::memcpy};
+
+/// Derives a `DK`-byte key from `password` and `salt` with PBKDF2-HMAC-SHA256.
+///
+/// Block `i` (counting from 1) of the derived key is `U(1) ^ U(2) ^ ... ^
+/// U(iterations)`, where `U(1) = HMAC-SHA256(password, salt || i)` with `i`
+/// encoded as a 32-bit big endian integer, and `U(j) = HMAC-SHA256(password,
+/// U(j - 1))`. If `DK` is not a multiple of 32, the last block is truncated.
+///
+/// Every iteration of every block costs two hash computations. In a `const`
+/// item, the compiler starts warning that evaluation is taking a long time
+/// after roughly a few hundred iterations in total, so large iteration counts
+/// are only practical when called at run time.
+///
+/// # Panics
+///
+/// Panics if `iterations` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::pbkdf2::pbkdf2_sha256;
+/// const KEY: [u8; 20] = pbkdf2_sha256(b"hunter2", b"NaCl", 16);
+///
+/// assert_eq!(
+///     hex::encode(&KEY[..]),
+///     "89000db00ac68db429b18e8c9cde665d2b5a4fa3"
+/// );
+/// ```
+#[must_use]
+pub const fn pbkdf2_sha256<const DK: usize>(
+    password: &[u8],
+    salt: &[u8],
+    iterations: u32,
+) -> [u8; DK] {
+    assert!(iterations != 0);
+
+    let key = PrecomputedHmacSha256::new(password);
+    let mut dk = [0; DK];
+    let mut offset = 0;
+    let mut i: u32 = 1;
+    while offset < DK {
+        let mut u = key.finalize(&key.inner().update(salt).update(&i.to_be_bytes()).finalize());
+        let mut block = u;
+        let mut j = 1;
+        while j < iterations {
+            u = key.mac(&u);
+            let mut k = 0;
+            while k < block.len() {
+                block[k] ^= u[k];
+                k += 1;
+            }
+            j += 1;
+        }
+
+        let n = if DK - offset < block.len() {
+            DK - offset
+        } else {
+            block.len()
+        };
+        memcpy(&mut dk, offset, &block, 0, n);
+        offset += n;
+        i += 1;
+    }
+
+    dk
+}
@@ -0,0 +1,50 @@
+//! The TLS 1.2 pseudorandom function (RFC 5246, section 5) with SHA-256.
+
+use crate::{hmac::PrecomputedHmacSha256, util::memcpy};
+
+/// Computes the TLS 1.2 PRF, `P_SHA256(secret, label || seed)`, deriving `L`
+/// bytes of output.
+///
+/// The output is the first `L` bytes of
+/// `HMAC-SHA256(secret, A(1) || label || seed) || HMAC-SHA256(secret, A(2) ||
+/// label || seed) || ...`, where `A(0) = label || seed` and
+/// `A(i) = HMAC-SHA256(secret, A(i - 1))`. Each block costs four hash
+/// computations, so large values of `L` may exceed the compiler's const
+/// evaluation limits.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::prf::tls12_prf_sha256;
+/// const OUTPUT: [u8; 40] = tls12_prf_sha256(b"secret", b"label", b"seed");
+///
+/// assert_eq!(
+///     hex::encode(&OUTPUT[..]),
+///     concat!(
+///         "7ed42a23a133ad379b99196a86db887cf595d9ada5661ec1186691659bf87a7a",
+///         "8d16f4320d6b5e9c"
+///     )
+/// );
+/// ```
+#[must_use]
+pub const fn tls12_prf_sha256<const L: usize>(secret: &[u8], label: &[u8], seed: &[u8]) -> [u8; L] {
+    let key = PrecomputedHmacSha256::new(secret);
+    let mut output = [0; L];
+    let mut a = key.finalize(&key.inner().update(label).update(seed).finalize());
+    let mut offset = 0;
+    while offset < L {
+        let inner = key.inner().update(&a).update(label).update(seed);
+        let block = key.finalize(&inner.finalize());
+
+        let n = if L - offset < block.len() {
+            L - offset
+        } else {
+            block.len()
+        };
+        memcpy(&mut output, offset, &block, 0, n);
+        offset += n;
+        a = key.mac(&a);
+    }
+
+    output
+}
@@ -0,0 +1,141 @@
+//! Hashing of regions of a buffer without copying them.
+//!
+//! Subslices are not supported in `const fn`, so these functions feed the
+//! relevant ranges of the buffer to the hash context by offset instead.
+
+use crate::Sha256;
+
+/// Computes the SHA-256 hash of `image`, treating the `hole_len` bytes starting
+/// at `hole_offset` as zero.
+///
+/// This supports images that embed their own hash: the region reserved for the
+/// hash is hashed as zeroes, without building a modified copy of the image.
+///
+/// # Panics
+///
+/// Panics if the hole extends past the end of `image`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::region::hash_with_hole;
+/// const IMAGE: [u8; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+/// const DIGEST: [u8; 32] = hash_with_hole(&IMAGE, 4, 6);
+///
+/// assert_eq!(
+///     hex::encode(&DIGEST[..]),
+///     "384e60ec734d7a9bf52ea93ea91eea0c9eb7f08081af68360c2169cc1efebb48"
+/// );
+/// ```
+#[must_use]
+pub const fn hash_with_hole<const M: usize>(
+    image: &[u8; M],
+    hole_offset: usize,
+    hole_len: usize,
+) -> [u8; 32] {
+    assert!(hole_offset <= M && hole_len <= M - hole_offset);
+
+    let hole_end = hole_offset + hole_len;
+    let mut hash = Sha256::new();
+    hash.inner.update_range(image, 0, hole_offset);
+    hash.inner.update_repeat(0, hole_len);
+    hash.inner.update_range(image, hole_end, M - hole_end);
+    hash.finalize()
+}
+
+/// Computes the SHA-256 hash of the concatenation of the `(offset, len)` runs
+/// of `base`, in order.
+///
+/// This mirrors hashing a POSIX `iovec` array: each run covers `len` bytes of
+/// `base` starting at `offset`, and runs may overlap or repeat.
+///
+/// # Panics
+///
+/// Panics if any run extends past the end of `base`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{region::hash_iovec, Sha256};
+/// const BASE: &[u8] = b"The quick brown fox jumps over the lazy dog";
+/// const DIGEST: [u8; 32] = hash_iovec(BASE, &[(4, 6), (16, 4)]);
+///
+/// assert_eq!(DIGEST, Sha256::new().update(b"quick fox ").finalize());
+/// ```
+#[must_use]
+pub const fn hash_iovec(base: &[u8], runs: &[(usize, usize)]) -> [u8; 32] {
+    let mut hash = Sha256::new();
+
+    let mut i = 0;
+    while i < runs.len() {
+        let (offset, len) = runs[i];
+        hash.inner.update_range(base, offset, len);
+        i += 1;
+    }
+
+    hash.finalize()
+}
+
+/// Computes the SHA-256 hash of the `window` bytes of `data` starting at
+/// `start`.
+///
+/// Scanning `start` across `data` and testing each digest against a mask
+/// gives a simple, if slow, way to find content-defined chunk boundaries.
+///
+/// # Panics
+///
+/// Panics if the window extends past the end of `data`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{region::sha256_of_window, Sha256};
+/// const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+/// const DIGEST: [u8; 32] = sha256_of_window(DATA, 16, 3);
+///
+/// assert_eq!(DIGEST, Sha256::new().update(b"fox").finalize());
+/// ```
+#[must_use]
+pub const fn sha256_of_window(data: &[u8], start: usize, window: usize) -> [u8; 32] {
+    let mut hash = Sha256::new();
+    hash.inner.update_range(data, start, window);
+    hash.finalize()
+}
+
+/// Computes the SHA-256 hash of the `len` bytes of the ring buffer `buf`
+/// starting at index `head`, wrapping around to the start of `buf` if they
+/// extend past its end.
+///
+/// # Panics
+///
+/// Panics if `len` is greater than the length of `buf`, or if `len` is
+/// nonzero and `head` is not an index into `buf`. An empty window is allowed
+/// for any `head`, including with an empty `buf`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{region::hash_ring, Sha256};
+/// const RING: &[u8] = b"rld!hello, wo";
+/// const DIGEST: [u8; 32] = hash_ring(RING, 4, 13);
+///
+/// assert_eq!(DIGEST, Sha256::new().update(b"hello, world!").finalize());
+/// ```
+#[must_use]
+pub const fn hash_ring(buf: &[u8], head: usize, len: usize) -> [u8; 32] {
+    assert!((len == 0 || head < buf.len()) && len <= buf.len());
+
+    let mut hash = Sha256::new();
+    if len == 0 {
+        return hash.finalize();
+    }
+
+    let first = if len < buf.len() - head {
+        len
+    } else {
+        buf.len() - head
+    };
+    hash.inner.update_range(buf, head, first);
+    hash.inner.update_range(buf, 0, len - first);
+    hash.finalize()
+}
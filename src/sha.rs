@@ -18,8 +18,10 @@ macro_rules! sha {
         $ssig0:tt,
         $ssig1:tt
     ) => {
-        #[derive(Clone)]
+        #[derive(Clone, Copy)]
         pub(crate) struct $name {
+            initial_state: [$word; 8],
+            initial_length: $length,
             state: [$word; 8],
             buffer: [u8; 16 * mem::size_of::<$word>()],
             offset: usize,
@@ -37,6 +39,8 @@ macro_rules! sha {
             /// Construct a new instance.
             pub(crate) const fn new(state: [$word; 8]) -> Self {
                 Self {
+                    initial_state: state,
+                    initial_length: 0,
                     state,
                     buffer: [0; Self::BLOCK_SIZE],
                     offset: 0,
@@ -44,38 +48,190 @@ macro_rules! sha {
                 }
             }
 
+            /// Construct an instance from a state and the length of input data in
+            /// bits.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `length` is not a whole number of blocks.
+            pub(crate) const fn from_midstate(state: [$word; 8], length: $length) -> Self {
+                assert!(length % (Self::BLOCK_SIZE as $length * 8) == 0);
+                Self {
+                    initial_state: state,
+                    initial_length: length,
+                    state,
+                    buffer: [0; Self::BLOCK_SIZE],
+                    offset: 0,
+                    length,
+                }
+            }
+
+            /// Returns the state and the length of input data in bits.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the input data is not a whole number of blocks.
+            pub(crate) const fn into_midstate(self) -> ([$word; 8], $length) {
+                assert!(self.offset == 0 && self.length % 8 == 0);
+                (self.state, self.length)
+            }
+
+            /// Restore the state and length that the instance was constructed with,
+            /// discarding any buffered input data.
+            pub(crate) const fn reset(&mut self) {
+                self.state = self.initial_state;
+                self.buffer = [0; Self::BLOCK_SIZE];
+                self.offset = 0;
+                self.length = self.initial_length;
+            }
+
             /// Add input data to the hash context.
             pub(crate) const fn update(&mut self, input: &[u8]) {
+                self.update_range(input, 0, input.len());
+            }
+
+            /// Add `len` bytes of input data from `input` (starting at `start`) to
+            /// the hash context.
+            ///
+            /// This function takes a `start` because subslices are not supported in
+            /// `const fn`.
+            pub(crate) const fn update_range(&mut self, input: &[u8], start: usize, len: usize) {
+                assert!(start <= input.len() && len <= input.len() - start);
+                self.assert_byte_aligned();
+
                 let offset = self.offset;
                 let needed = Self::BLOCK_SIZE - offset;
 
-                if needed > input.len() {
-                    memcpy(&mut self.buffer, offset, input, 0, input.len());
-                    self.offset += input.len();
+                if needed > len {
+                    memcpy(&mut self.buffer, offset, input, start, len);
+                    self.offset += len;
                 } else {
-                    memcpy(&mut self.buffer, offset, input, 0, needed);
+                    memcpy(&mut self.buffer, offset, input, start, needed);
                     Self::compress(&mut self.state, &self.buffer, 0);
 
-                    let mut i = needed;
-                    loop {
-                        let remain = input.len() - i;
-                        if remain < Self::BLOCK_SIZE {
-                            memcpy(&mut self.buffer, 0, input, i, remain);
-                            self.offset = remain;
-                            break;
-                        } else {
-                            Self::compress(&mut self.state, input, i);
-                            i += Self::BLOCK_SIZE;
-                        }
-                    }
+                    let blocks = (len - needed) / Self::BLOCK_SIZE;
+                    Self::compress_blocks(&mut self.state, input, start + needed, blocks);
+
+                    let i = needed + blocks * Self::BLOCK_SIZE;
+                    let remain = len - i;
+                    memcpy(&mut self.buffer, 0, input, start + i, remain);
+                    self.offset = remain;
+                }
+
+                self.length += (len as $length) * 8;
+            }
+
+            /// Add a single byte of input data to the hash context.
+            ///
+            /// This avoids the copy in [`update_range`](Self::update_range).
+            pub(crate) const fn update_byte(&mut self, byte: u8) {
+                self.assert_byte_aligned();
+                self.buffer[self.offset] = byte;
+                self.offset += 1;
+                if self.offset == Self::BLOCK_SIZE {
+                    Self::compress(&mut self.state, &self.buffer, 0);
+                    self.offset = 0;
+                }
+
+                self.length += 8;
+            }
+
+            /// Add the first `bit_len` bits of `input` to the hash context.
+            ///
+            /// If `bit_len` is not a multiple of 8, the remaining bits are taken from
+            /// the most significant bits of the last byte, and no more input data can
+            /// be added.
+            pub(crate) const fn update_bits(&mut self, input: &[u8], bit_len: usize) {
+                assert!(
+                    bit_len.div_ceil(8) <= input.len(),
+                    "input is shorter than bit_len bits"
+                );
+                let len = bit_len / 8;
+                self.update_range(input, 0, len);
+
+                let bits = bit_len % 8;
+                if bits != 0 {
+                    // The partial byte is kept at `offset`, which only counts whole
+                    // bytes, and its bits are only included in `length`.
+                    self.buffer[self.offset] = input[len] & !(0xff >> bits);
+                    self.length += bits as $length;
+                }
+            }
+
+            const fn assert_byte_aligned(&self) {
+                if self.length % 8 != 0 {
+                    panic!("input data added after a partial byte");
+                }
+            }
+
+            /// Add `n` copies of `val` to the hash context.
+            pub(crate) const fn update_repeat(&mut self, val: u8, n: usize) {
+                let block = [val; Self::BLOCK_SIZE];
+                let mut remain = n;
+                while remain > 0 {
+                    let len = if remain < Self::BLOCK_SIZE {
+                        remain
+                    } else {
+                        Self::BLOCK_SIZE
+                    };
+                    self.update_range(&block, 0, len);
+                    remain -= len;
                 }
+            }
+
+            /// Returns the number of bytes of input data added so far.
+            pub(crate) const fn byte_len(&self) -> $length {
+                self.length / 8
+            }
 
-                self.length += (input.len() as $length) * 8;
+            /// Returns the number of bits of input data added so far.
+            pub(crate) const fn bit_len(&self) -> $length {
+                self.length
+            }
+
+            /// Returns the length of a message of `message_len` bytes after padding.
+            pub(crate) const fn padded_len(message_len: u64) -> u64 {
+                let block_size = Self::BLOCK_SIZE as u64;
+                let min_len = message_len.checked_add(1 + Self::LENGTH_SIZE as u64);
+                let padded = match min_len {
+                    Some(min_len) => min_len.div_ceil(block_size).checked_mul(block_size),
+                    None => None,
+                };
+                match padded {
+                    Some(padded) => padded,
+                    None => panic!("padded length overflows u64"),
+                }
             }
 
-            pub(crate) const fn finalize(mut self) -> [u8; Self::DIGEST_SIZE] {
+            /// Returns the number of bytes buffered awaiting a full block.
+            pub(crate) const fn buffered_bytes(&self) -> usize {
+                self.offset
+            }
+
+            pub(crate) const fn finalize(self) -> [u8; Self::DIGEST_SIZE] {
+                let state = self.finalize_state();
+
+                let mut digest = [0; Self::DIGEST_SIZE];
+                let mut i = 0;
+                while i < state.len() {
+                    $store_word(&mut digest, i * Self::WORD_SIZE, state[i]);
+                    i += 1
+                }
+
+                digest
+            }
+
+            /// Pad the message and return the final state words, before they are
+            /// serialized into the digest.
+            pub(crate) const fn finalize_state(mut self) -> [$word; 8] {
                 let mut offset = self.offset;
-                self.buffer[offset] = 0x80;
+                let bits = (self.length % 8) as u32;
+                if bits == 0 {
+                    self.buffer[offset] = 0x80;
+                } else {
+                    // Append the 1 bit after the partial byte's bits.
+                    self.buffer[offset] |= 0x80 >> bits;
+                }
                 offset += 1;
 
                 if offset > Self::LENGTH_OFFSET {
@@ -88,21 +244,29 @@ macro_rules! sha {
                 $store_length(&mut self.buffer, Self::LENGTH_OFFSET, self.length);
                 Self::compress(&mut self.state, &self.buffer, 0);
 
-                let mut digest = [0; Self::DIGEST_SIZE];
-                let mut i = 0;
-                while i < self.state.len() {
-                    $store_word(&mut digest, i * Self::WORD_SIZE, self.state[i]);
-                    i += 1
-                }
-
-                digest
+                self.state
             }
 
             /// SHA compression function.
             ///
             /// This function takes an `offset` because subslices are not supported in
             /// `const fn`.
-            const fn compress(state: &mut [$word; 8], buffer: &[u8], offset: usize) {
+            pub(crate) const fn compress(state: &mut [$word; 8], buffer: &[u8], offset: usize) {
+                Self::compress_blocks(state, buffer, offset, 1);
+            }
+
+            /// Apply the SHA compression function to `n_blocks` consecutive blocks
+            /// of `input`, starting at `offset`.
+            ///
+            /// Looping here, rather than calling [`compress`](Self::compress) for
+            /// each block, reduces the overhead of bulk updates in const
+            /// evaluation.
+            pub(crate) const fn compress_blocks(
+                state: &mut [$word; 8],
+                input: &[u8],
+                offset: usize,
+                n_blocks: usize,
+            ) {
                 #[inline(always)]
                 const fn ch(x: $word, y: $word, z: $word) -> $word {
                     (x & y) ^ ((!x) & z)
@@ -128,59 +292,68 @@ macro_rules! sha {
                     x.rotate_right($ssig1.0) ^ x.rotate_right($ssig1.1) ^ (x >> $ssig1.2)
                 }
 
-                let mut w: [$word; $k.len()] = [0; $k.len()];
+                let mut block = 0;
+                while block < n_blocks {
+                    let block_offset = offset + block * Self::BLOCK_SIZE;
 
-                let mut i = 0;
-                while i < 16 {
-                    w[i] = $load_word(buffer, offset + i * mem::size_of::<$word>());
-                    i += 1;
-                }
-                while i < $k.len() {
-                    w[i] = sigma1(w[i - 2])
-                        .wrapping_add(w[i - 7])
-                        .wrapping_add(sigma0(w[i - 15]))
-                        .wrapping_add(w[i - 16]);
-                    i += 1;
-                }
-
-                let mut a = state[0];
-                let mut b = state[1];
-                let mut c = state[2];
-                let mut d = state[3];
-                let mut e = state[4];
-                let mut f = state[5];
-                let mut g = state[6];
-                let mut h = state[7];
+                    // The message schedule is expanded on the fly, keeping only the last
+                    // 16 words, to reduce the working set during const evaluation.
+                    let mut w: [$word; 16] = [0; 16];
 
-                let mut i = 0;
-                while i < $k.len() {
-                    let t1 = h
-                        .wrapping_add(big_sigma1(e))
-                        .wrapping_add(ch(e, f, g))
-                        .wrapping_add($k[i])
-                        .wrapping_add(w[i]);
-                    let t2 = big_sigma0(a).wrapping_add(maj(a, b, c));
-
-                    h = g;
-                    g = f;
-                    f = e;
-                    e = d.wrapping_add(t1);
-                    d = c;
-                    c = b;
-                    b = a;
-                    a = t1.wrapping_add(t2);
-
-                    i += 1;
-                }
-
-                state[0] = state[0].wrapping_add(a);
-                state[1] = state[1].wrapping_add(b);
-                state[2] = state[2].wrapping_add(c);
-                state[3] = state[3].wrapping_add(d);
-                state[4] = state[4].wrapping_add(e);
-                state[5] = state[5].wrapping_add(f);
-                state[6] = state[6].wrapping_add(g);
-                state[7] = state[7].wrapping_add(h);
+                    let mut i = 0;
+                    while i < 16 {
+                        w[i] = $load_word(input, block_offset + i * Self::WORD_SIZE);
+                        i += 1;
+                    }
+
+                    let mut a = state[0];
+                    let mut b = state[1];
+                    let mut c = state[2];
+                    let mut d = state[3];
+                    let mut e = state[4];
+                    let mut f = state[5];
+                    let mut g = state[6];
+                    let mut h = state[7];
+
+                    let mut i = 0;
+                    while i < $k.len() {
+                        if i >= 16 {
+                            w[i & 15] = sigma1(w[(i - 2) & 15])
+                                .wrapping_add(w[(i - 7) & 15])
+                                .wrapping_add(sigma0(w[(i - 15) & 15]))
+                                .wrapping_add(w[i & 15]);
+                        }
+
+                        let t1 = h
+                            .wrapping_add(big_sigma1(e))
+                            .wrapping_add(ch(e, f, g))
+                            .wrapping_add($k[i])
+                            .wrapping_add(w[i & 15]);
+                        let t2 = big_sigma0(a).wrapping_add(maj(a, b, c));
+
+                        h = g;
+                        g = f;
+                        f = e;
+                        e = d.wrapping_add(t1);
+                        d = c;
+                        c = b;
+                        b = a;
+                        a = t1.wrapping_add(t2);
+
+                        i += 1;
+                    }
+
+                    state[0] = state[0].wrapping_add(a);
+                    state[1] = state[1].wrapping_add(b);
+                    state[2] = state[2].wrapping_add(c);
+                    state[3] = state[3].wrapping_add(d);
+                    state[4] = state[4].wrapping_add(e);
+                    state[5] = state[5].wrapping_add(f);
+                    state[6] = state[6].wrapping_add(g);
+                    state[7] = state[7].wrapping_add(h);
+
+                    block += 1;
+                }
             }
         }
     };
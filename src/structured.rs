@@ -0,0 +1,245 @@
+//! Hashing of structured data with unambiguous framing.
+//!
+//! Each function here encodes its input in a fixed, documented way before
+//! hashing it with SHA-256, so that structurally different inputs cannot
+//! produce the same byte stream.
+
+use crate::Sha256;
+
+/// Computes the SHA-256 hash of a `R` by `C` matrix of `u32` values.
+///
+/// The dimensions `R` and `C` are hashed first, each as a 32-bit big endian
+/// integer, followed by every element in row-major order, also as a 32-bit big
+/// endian integer. Binding the dimensions means that, for example, a 2x3 matrix
+/// and a 3x2 matrix with the same elements have different hashes.
+///
+/// # Panics
+///
+/// Panics if either dimension does not fit in a `u32`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::structured::hash_matrix;
+/// const DIGEST: [u8; 32] = hash_matrix(&[[1, 2, 3], [4, 5, 6]]);
+///
+/// assert_eq!(
+///     hex::encode(&DIGEST[..]),
+///     "1f7915139ac3c7f0ba884ad5c2e47440e1eb634a6e758e492b7badf34d9c9568"
+/// );
+/// ```
+#[must_use]
+pub const fn hash_matrix<const R: usize, const C: usize>(m: &[[u32; C]; R]) -> [u8; 32] {
+    assert!(R <= u32::MAX as usize && C <= u32::MAX as usize);
+
+    let mut hash = Sha256::new()
+        .update(&(R as u32).to_be_bytes())
+        .update(&(C as u32).to_be_bytes());
+
+    let mut i = 0;
+    while i < R {
+        let mut j = 0;
+        while j < C {
+            hash = hash.update(&m[i][j].to_be_bytes());
+            j += 1;
+        }
+        i += 1;
+    }
+
+    hash.finalize()
+}
+
+/// Computes the SHA-256 hash of a list of byte strings.
+///
+/// Each item is hashed as its length in bytes, encoded as a 64-bit big endian
+/// integer, followed by its contents. This makes the encoding unambiguous:
+/// `["ab", "c"]`, `["a", "bc"]` and `["abc"]` all have different hashes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::structured::tuple_hash;
+/// const DIGEST: [u8; 32] = tuple_hash(&[b"alice", b"", b"bob"]);
+///
+/// assert_eq!(
+///     hex::encode(&DIGEST[..]),
+///     "21f0fd141d01d36bd1d3f645f9f6d011d305394d1fe8d06992b35c7870890b37"
+/// );
+/// ```
+#[must_use]
+pub const fn tuple_hash(items: &[&[u8]]) -> [u8; 32] {
+    let mut hash = Sha256::new();
+
+    let mut i = 0;
+    while i < items.len() {
+        hash = hash
+            .update(&(items[i].len() as u64).to_be_bytes())
+            .update(items[i]);
+        i += 1;
+    }
+
+    hash.finalize()
+}
+
+/// Computes the SHA-256 hash of a snapshot of 32-bit registers.
+///
+/// Each register is hashed in order as a 32-bit integer, in big endian byte
+/// order if `big_endian` is `true` and little endian byte order otherwise.
+/// Unlike [`hash_matrix`], the number of registers is not hashed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::structured::checksum_registers;
+/// const REGS: [u32; 3] = [0xdeadbeef, 0x12345678, 0];
+/// const BE: [u8; 32] = checksum_registers(&REGS, true);
+/// const LE: [u8; 32] = checksum_registers(&REGS, false);
+///
+/// assert_eq!(
+///     hex::encode(&BE[..]),
+///     "98230b0c337e55c8851c41a508f84a45b3da1c2d2ced8f8ea2fb89e35255b9cc"
+/// );
+/// assert_eq!(
+///     hex::encode(&LE[..]),
+///     "a57021d6855e9c89e9ebf3346e7df87ecb0b3cdbd2cb40a829bd4908681fdd94"
+/// );
+/// ```
+#[must_use]
+pub const fn checksum_registers<const N: usize>(regs: &[u32; N], big_endian: bool) -> [u8; 32] {
+    let mut hash = Sha256::new();
+
+    let mut i = 0;
+    while i < N {
+        let bytes = if big_endian {
+            regs[i].to_be_bytes()
+        } else {
+            regs[i].to_le_bytes()
+        };
+        hash = hash.update(&bytes);
+        i += 1;
+    }
+
+    hash.finalize()
+}
+
+/// Computes the SHA-256 hash of a variant of a tagged union with a fixed-size
+/// payload.
+///
+/// The tag byte is hashed first, followed by the payload. Within a union where
+/// each variant has a distinct tag, variants therefore never hash the same,
+/// even if their payloads are identical. Payloads of different sizes are not
+/// framed, so every variant sharing a tag must use the same payload size `P`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::structured::hash_tagged;
+/// const DIGEST: [u8; 32] = hash_tagged(2, &[0xde, 0xad, 0xbe, 0xef]);
+///
+/// assert_eq!(
+///     hex::encode(&DIGEST[..]),
+///     "bb6724ac0cbf7e07d33094a5dbb1098c1ae85f295349be140405fb2568c96ae3"
+/// );
+/// ```
+#[must_use]
+pub const fn hash_tagged<const P: usize>(tag: u8, payload: &[u8; P]) -> [u8; 32] {
+    Sha256::new().update(&[tag]).update(payload).finalize()
+}
+
+/// Computes the SHA-256 hash of a build identity, `name@version+git`.
+///
+/// The separators are only unambiguous if `name` does not contain `@` and
+/// `version` does not contain `+`, so both are checked. `git` is typically a
+/// commit ID, and may contain any characters.
+///
+/// # Panics
+///
+/// Panics if `name` contains `@` or `version` contains `+`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::structured::hash_version_triple;
+/// const BUILD_ID: [u8; 32] = hash_version_triple("sha2-const", "0.1.2", "f12bc54");
+///
+/// assert_eq!(
+///     hex::encode(&BUILD_ID[..]),
+///     "22ce8284fb56e272093614d454c1401cba517845c61c686064afc8c97b75863a"
+/// );
+/// ```
+#[must_use]
+pub const fn hash_version_triple(name: &str, version: &str, git: &str) -> [u8; 32] {
+    assert!(!contains(name.as_bytes(), b'@') && !contains(version.as_bytes(), b'+'));
+
+    Sha256::new()
+        .update(name.as_bytes())
+        .update(b"@")
+        .update(version.as_bytes())
+        .update(b"+")
+        .update(git.as_bytes())
+        .finalize()
+}
+
+/// Computes the SHA-256 hash of `input` bound to a bitmask of build flags.
+///
+/// `flags` is hashed first, as a 64-bit big endian integer, followed by
+/// `input`. Builds of the same input with different flags therefore produce
+/// different hashes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::structured::hash_with_flags;
+/// const FEATURE_STD: u64 = 1 << 0;
+/// const FEATURE_SERDE: u64 = 1 << 2;
+/// const BUILD_ID: [u8; 32] = hash_with_flags(b"build", FEATURE_STD | FEATURE_SERDE);
+///
+/// assert_eq!(
+///     hex::encode(&BUILD_ID[..]),
+///     "666e1aa72b1aba66fede6ed53c9482a71f7c99ab9d0dac852af4f85cf0dabbd8"
+/// );
+/// ```
+#[must_use]
+pub const fn hash_with_flags(input: &[u8], flags: u64) -> [u8; 32] {
+    Sha256::new()
+        .update(&flags.to_be_bytes())
+        .update(input)
+        .finalize()
+}
+
+/// Computes the SHA-256 hash of `input` bound to a schema version.
+///
+/// `schema_version` is hashed first, as a 16-bit big endian integer, followed
+/// by `input`. The same bytes interpreted under different schema versions
+/// therefore produce different hashes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::structured::versioned_hash;
+/// const DIGEST: [u8; 32] = versioned_hash(3, br#"{"id":1}"#);
+///
+/// assert_eq!(
+///     hex::encode(&DIGEST[..]),
+///     "2f3bb0df57d8d3fac54e6a6690ce27ce23386c4da6d57ab029d7b3528953af08"
+/// );
+/// ```
+#[must_use]
+pub const fn versioned_hash(schema_version: u16, input: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .update(&schema_version.to_be_bytes())
+        .update(input)
+        .finalize()
+}
+
+const fn contains(s: &[u8], byte: u8) -> bool {
+    let mut i = 0;
+    while i < s.len() {
+        if s[i] == byte {
+            return true;
+        }
+        i += 1;
+    }
+
+    false
+}
@@ -0,0 +1,49 @@
+//! Implementations of the [`digest`] crate traits, for use at run time.
+
+use crate::{Sha224, Sha256, Sha384, Sha512, Sha512_224, Sha512_256};
+use core::mem;
+use digest::{
+    consts::{U28, U32, U48, U64},
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+macro_rules! traits {
+    ($name:ident, $size:ty) => {
+        impl HashMarker for $name {}
+
+        impl OutputSizeUser for $name {
+            type OutputSize = $size;
+        }
+
+        impl Update for $name {
+            fn update(&mut self, data: &[u8]) {
+                *self = mem::take(self).update(data);
+            }
+        }
+
+        impl FixedOutput for $name {
+            fn finalize_into(self, out: &mut Output<Self>) {
+                out.copy_from_slice(&self.finalize());
+            }
+        }
+
+        impl FixedOutputReset for $name {
+            fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+                out.copy_from_slice(&self.finalize_reset());
+            }
+        }
+
+        impl Reset for $name {
+            fn reset(&mut self) {
+                $name::reset(self);
+            }
+        }
+    };
+}
+
+traits!(Sha224, U28);
+traits!(Sha256, U32);
+traits!(Sha384, U48);
+traits!(Sha512, U64);
+traits!(Sha512_224, U28);
+traits!(Sha512_256, U32);
@@ -30,6 +30,57 @@ pub(crate) const fn memset(dest: &mut [u8], offset: usize, val: u8, n: usize) {
     }
 }
 
+/// Returns `true` if `a` and `b` have the same length and contents.
+///
+/// This function exists because slice comparison is not supported in `const
+/// fn`. It is not constant-time.
+#[inline(always)]
+pub(crate) const fn memeq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Returns `true` if `a` and `b` have the same contents, in constant time.
+///
+/// Every byte is examined regardless of where the first difference is, and the
+/// differences are combined without branching, so the running time does not
+/// depend on the contents. Use this instead of `==` to compare secret values
+/// such as MACs at run time. Timing is not a concern during const evaluation,
+/// but the same function can be used in both contexts.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{ct_eq, Sha256};
+/// const EXPECTED: [u8; 32] = Sha256::new().update(b"abc").finalize();
+///
+/// assert!(ct_eq(&Sha256::new().update(b"abc").finalize(), &EXPECTED));
+/// assert!(!ct_eq(&Sha256::new().update(b"abd").finalize(), &EXPECTED));
+/// ```
+#[must_use]
+#[inline(always)]
+pub const fn ct_eq<const N: usize>(a: &[u8; N], b: &[u8; N]) -> bool {
+    let mut diff = 0;
+    let mut i = 0;
+    while i < N {
+        diff |= a[i] ^ b[i];
+        i += 1;
+    }
+
+    diff == 0
+}
+
 /// Loads an unsigned 32-bit big endian integer from `src` (starting at
 /// `offset`).
 ///
@@ -91,3 +142,408 @@ pub(crate) const fn store_u128_be(dest: &mut [u8], offset: usize, n: u128) {
     let bytes = u128::to_be_bytes(n);
     memcpy(dest, offset, &bytes, 0, bytes.len());
 }
+
+/// Formats `n` as ASCII decimal digits.
+///
+/// Returns a buffer containing the digits, without leading zeroes, and the
+/// number of digits at the start of the buffer that are significant. Zero is
+/// formatted as a single `0` digit.
+pub(crate) const fn u64_to_decimal(n: u64) -> ([u8; 20], usize) {
+    // u64::MAX has 20 decimal digits.
+    let mut digits = [0; 20];
+    let mut len = 0;
+    let mut rest = n;
+    loop {
+        digits[len] = b'0' + (rest % 10) as u8;
+        len += 1;
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+
+    // The digits were produced least significant first.
+    let mut i = 0;
+    while i < len / 2 {
+        let tmp = digits[i];
+        digits[i] = digits[len - 1 - i];
+        digits[len - 1 - i] = tmp;
+        i += 1;
+    }
+
+    (digits, len)
+}
+
+/// Panics with `message` followed by `index` formatted as decimal digits.
+///
+/// This function exists because formatting arguments other than a single
+/// `&str` is not supported in `const fn`.
+pub(crate) const fn panic_at_index(message: &str, index: usize) -> ! {
+    const MAX_MESSAGE_LEN: usize = 64;
+    assert!(message.len() <= MAX_MESSAGE_LEN);
+
+    let (digits, digits_len) = u64_to_decimal(index as u64);
+    let mut buffer = [0; MAX_MESSAGE_LEN + 20];
+    memcpy(&mut buffer, 0, message.as_bytes(), 0, message.len());
+    memcpy(&mut buffer, message.len(), &digits, 0, digits_len);
+
+    let (buffer, _) = buffer.split_at(message.len() + digits_len);
+    match core::str::from_utf8(buffer) {
+        Ok(s) => panic!("{}", s),
+        Err(_) => panic!("{}", message),
+    }
+}
+
+/// Encodes `bytes` as lowercase hex.
+///
+/// `H` must be exactly `N * 2`. The output is ASCII, so it can be converted to
+/// a `&str` with [`core::str::from_utf8`], which is also a `const fn`.
+///
+/// # Panics
+///
+/// Panics if `H` is not `N * 2`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::to_hex;
+/// const HEX: [u8; 8] = to_hex(&[0xde, 0xad, 0xbe, 0xef]);
+///
+/// assert_eq!(&HEX, b"deadbeef");
+/// ```
+#[must_use]
+pub const fn to_hex<const N: usize, const H: usize>(bytes: &[u8; N]) -> [u8; H] {
+    encode_hex(bytes, b"0123456789abcdef")
+}
+
+/// Encodes `bytes` as uppercase hex.
+///
+/// This is identical to [`to_hex`], except for the case of the letters.
+///
+/// # Panics
+///
+/// Panics if `H` is not `N * 2`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::to_hex_upper;
+/// const HEX: [u8; 8] = to_hex_upper(&[0xde, 0xad, 0xbe, 0xef]);
+///
+/// assert_eq!(&HEX, b"DEADBEEF");
+/// ```
+#[must_use]
+pub const fn to_hex_upper<const N: usize, const H: usize>(bytes: &[u8; N]) -> [u8; H] {
+    encode_hex(bytes, b"0123456789ABCDEF")
+}
+
+const fn encode_hex<const N: usize, const H: usize>(bytes: &[u8; N], digits: &[u8; 16]) -> [u8; H] {
+    assert!(H == N * 2);
+
+    let mut out = [0; H];
+    let mut i = 0;
+    while i < N {
+        out[2 * i] = digits[(bytes[i] >> 4) as usize];
+        out[2 * i + 1] = digits[(bytes[i] & 0xf) as usize];
+        i += 1;
+    }
+
+    out
+}
+
+/// Decodes `hex`, which must be exactly `N * 2` hex digits, into `N` bytes.
+///
+/// Both lowercase and uppercase digits are accepted. This allows expected
+/// digests to be written inline in constant expressions.
+///
+/// # Panics
+///
+/// Panics if `hex` is not `N * 2` bytes long, or if it contains a byte that is
+/// not a hex digit.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::{ct_eq, from_hex, Sha256};
+/// const DIGEST: [u8; 32] = Sha256::new().update(b"abc").finalize();
+/// const _: () = assert!(ct_eq(
+///     &DIGEST,
+///     &from_hex(b"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+/// ));
+/// ```
+#[must_use]
+pub const fn from_hex<const N: usize>(hex: &[u8]) -> [u8; N] {
+    if hex.len() != N * 2 {
+        panic!("hex string has the wrong length");
+    }
+
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < hex.len() {
+        let digit = match hex_digit(hex[i]) {
+            Some(digit) => digit,
+            None => panic_at_index("invalid hex digit at index ", i),
+        };
+        out[i / 2] = (out[i / 2] << 4) | digit;
+        i += 1;
+    }
+
+    out
+}
+
+/// Returns the value of the hex digit `c`, or `None` if it is not a hex digit.
+pub(crate) const fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Encodes `bytes` as uppercase Crockford base32.
+///
+/// Crockford's alphabet omits the letters `I`, `L`, `O` and `U` to avoid
+/// confusion with digits and accidental words. The output has no padding
+/// characters: if the number of bits is not a multiple of five, the final
+/// character is padded with zero bits. `B` must be exactly `(N * 8 + 4) / 5`,
+/// for example 52 for a 32-byte digest.
+///
+/// # Panics
+///
+/// Panics if `B` is not `(N * 8 + 4) / 5`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::to_base32_crockford;
+/// const ID: [u8; 7] = to_base32_crockford(&[0xde, 0xad, 0xbe, 0xef]);
+///
+/// assert_eq!(&ID, b"VTPVXVR");
+/// ```
+#[must_use]
+pub const fn to_base32_crockford<const N: usize, const B: usize>(bytes: &[u8; N]) -> [u8; B] {
+    const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+    assert!(B == (N * 8).div_ceil(5));
+
+    let mut out = [0; B];
+    let mut acc: u16 = 0;
+    let mut bits = 0;
+    let mut len = 0;
+    let mut i = 0;
+    while i < N {
+        acc = (acc << 8) | bytes[i] as u16;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out[len] = ALPHABET[((acc >> bits) & 0x1f) as usize];
+            len += 1;
+        }
+        i += 1;
+    }
+    if bits > 0 {
+        out[len] = ALPHABET[((acc << (5 - bits)) & 0x1f) as usize];
+    }
+
+    out
+}
+
+/// Encodes `bytes` as standard base64 (RFC 4648, section 4), with `=`
+/// padding.
+///
+/// `B` must be exactly `N.div_ceil(3) * 4`, for example 44 for a 32-byte
+/// digest.
+///
+/// # Panics
+///
+/// Panics if `B` is not `N.div_ceil(3) * 4`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::to_base64;
+/// const ID: [u8; 8] = to_base64(&[0xde, 0xad, 0xbe, 0xef]);
+///
+/// assert_eq!(&ID, b"3q2+7w==");
+/// ```
+#[must_use]
+pub const fn to_base64<const N: usize, const B: usize>(bytes: &[u8; N]) -> [u8; B] {
+    assert!(B == N.div_ceil(3) * 4);
+    encode_base64(
+        bytes,
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+    )
+}
+
+/// Encodes `bytes` as URL-safe base64 (RFC 4648, section 5), without
+/// padding.
+///
+/// This is the encoding used by JSON Web Tokens. `B` must be exactly
+/// `(N * 8).div_ceil(6)`, for example 43 for a 32-byte digest.
+///
+/// # Panics
+///
+/// Panics if `B` is not `(N * 8).div_ceil(6)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::to_base64_url;
+/// const ID: [u8; 6] = to_base64_url(&[0xde, 0xad, 0xbe, 0xef]);
+///
+/// assert_eq!(&ID, b"3q2-7w");
+/// ```
+#[must_use]
+pub const fn to_base64_url<const N: usize, const B: usize>(bytes: &[u8; N]) -> [u8; B] {
+    assert!(B == (N * 8).div_ceil(6));
+    encode_base64(
+        bytes,
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+    )
+}
+
+/// Encodes `bytes` with the base64 `alphabet`, filling any space left in the
+/// output with `=` padding.
+const fn encode_base64<const N: usize, const B: usize>(
+    bytes: &[u8; N],
+    alphabet: &[u8; 64],
+) -> [u8; B] {
+    let mut out = [b'='; B];
+    let mut acc: u16 = 0;
+    let mut bits = 0;
+    let mut len = 0;
+    let mut i = 0;
+    while i < N {
+        acc = (acc << 8) | bytes[i] as u16;
+        bits += 8;
+        while bits >= 6 {
+            bits -= 6;
+            out[len] = alphabet[((acc >> bits) & 0x3f) as usize];
+            len += 1;
+        }
+        i += 1;
+    }
+    if bits > 0 {
+        out[len] = alphabet[((acc << (6 - bits)) & 0x3f) as usize];
+    }
+
+    out
+}
+
+/// Decodes the base64 body of the first PEM block in `pem`.
+///
+/// The armor lines (`-----BEGIN ...-----` and `-----END ...-----`) are
+/// stripped, along with any whitespace in the body, and the remainder is
+/// decoded as padded standard base64 (RFC 4648, section 4). Text before the
+/// `BEGIN` line and after the `END` line is ignored. PEM headers, such as
+/// those used by RFC 1421 encrypted keys, are not supported. `B` must be
+/// exactly the length of the decoded body.
+///
+/// # Panics
+///
+/// Panics if `pem` does not contain a PEM block, if the body is not valid
+/// base64 (including missing or misplaced padding, a dangling final
+/// character, or non-zero trailing bits), or if it does not decode to exactly
+/// `B` bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sha2_const::pem_body_bytes;
+/// const PEM: &str = "-----BEGIN MESSAGE-----
+/// aGVsbG8s
+/// IHdvcmxk
+/// -----END MESSAGE-----
+/// ";
+/// const BODY: [u8; 12] = pem_body_bytes(PEM);
+///
+/// assert_eq!(&BODY, b"hello, world");
+/// ```
+#[must_use]
+pub const fn pem_body_bytes<const B: usize>(pem: &str) -> [u8; B] {
+    const BEGIN: &[u8] = b"-----BEGIN ";
+    const END: &[u8] = b"-----END ";
+
+    let pem = pem.as_bytes();
+
+    // Find the BEGIN line, and skip to the start of the next line.
+    let mut i = 0;
+    while !starts_with(pem, i, BEGIN) {
+        assert!(i < pem.len(), "missing PEM BEGIN line");
+        i += 1;
+    }
+    while pem[i] != b'\n' {
+        i += 1;
+        assert!(i < pem.len(), "missing PEM body");
+    }
+
+    let mut out = [0; B];
+    let mut len = 0;
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    let mut data_chars = 0;
+    let mut padding = 0;
+    while !starts_with(pem, i, END) {
+        assert!(i < pem.len(), "missing PEM END line");
+        let c = pem[i];
+        i += 1;
+
+        let value = match c {
+            b' ' | b'\t' | b'\r' | b'\n' => continue,
+            b'=' => {
+                padding += 1;
+                continue;
+            },
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => panic!("invalid base64 in PEM body"),
+        };
+        assert!(padding == 0, "invalid base64 padding in PEM body");
+
+        data_chars += 1;
+        acc = (acc << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            assert!(len < B, "PEM body is too long");
+            out[len] = (acc >> bits) as u8;
+            len += 1;
+        }
+    }
+    // A single trailing character cannot encode a whole byte, and the unused
+    // bits of the final character must be zero (RFC 4648, section 3.5).
+    assert!(bits < 6, "invalid base64 length in PEM body");
+    assert!(
+        acc & ((1 << bits) - 1) == 0,
+        "non-zero trailing bits in PEM body"
+    );
+    assert!(
+        padding == (4 - data_chars % 4) % 4,
+        "invalid base64 padding in PEM body"
+    );
+    assert!(len == B, "PEM body is too short");
+
+    out
+}
+
+/// Returns `true` if `bytes` contains `prefix` starting at `offset`.
+///
+/// This function exists because subslices are not supported in `const fn`.
+const fn starts_with(bytes: &[u8], offset: usize, prefix: &[u8]) -> bool {
+    if offset > bytes.len() || bytes.len() - offset < prefix.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < prefix.len() {
+        if bytes[offset + i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
@@ -0,0 +1,18 @@
+#![cfg(feature = "alloc")]
+
+use sha2_const::{Sha224, Sha256, Sha512};
+
+#[test]
+fn finalize_vec() {
+    let digests: Vec<Vec<u8>> = (0..3)
+        .map(|i| match i {
+            0 => Sha224::new().update(b"abc").finalize_vec(),
+            1 => Sha256::new().update(b"abc").finalize_vec(),
+            _ => Sha512::new().update(b"abc").finalize_vec(),
+        })
+        .collect();
+
+    assert_eq!(digests[0], Sha224::new().update(b"abc").finalize());
+    assert_eq!(digests[1], Sha256::new().update(b"abc").finalize());
+    assert_eq!(digests[2], Sha512::new().update(b"abc").finalize());
+}
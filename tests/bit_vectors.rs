@@ -0,0 +1,142 @@
+//! Messages whose length is not a multiple of 8 bits.
+//!
+//! These tests check `update_bits` against messages padded by hand and hashed
+//! with the compression function directly, so they only show that the two
+//! agree; they are not an external reference. `test_vectors.rs` runs the
+//! single NIST bit-oriented vector in `tests/data/bit` (SHA-256, `Len = 1`).
+
+use proptest::{arbitrary::any, prop_assert_eq, proptest};
+use sha2_const::{
+    compress_block, compress_block_512,
+    constants::{H224, H256, H384, H512},
+    Sha224, Sha256, Sha384, Sha512,
+};
+use std::convert::TryInto;
+
+const MAX_BITS: usize = 2048;
+
+/// Pads the first `bit_len` bits of `input` as specified by FIPS 180-4,
+/// section 5.1, with a length field of `length_size` bytes.
+fn pad(input: &[u8], bit_len: usize, block_size: usize, length_size: usize) -> Vec<u8> {
+    let mut padded = input[..bit_len / 8].to_vec();
+    let bits = bit_len % 8;
+    let last = if bits == 0 {
+        0x80
+    } else {
+        (input[bit_len / 8] & !(0xff >> bits)) | (0x80 >> bits)
+    };
+    padded.push(last);
+    while !(padded.len() + length_size).is_multiple_of(block_size) {
+        padded.push(0);
+    }
+    padded.extend_from_slice(&(bit_len as u128).to_be_bytes()[16 - length_size..]);
+    padded
+}
+
+fn reference_256(iv: [u32; 8], input: &[u8], bit_len: usize) -> Vec<u8> {
+    let state = pad(input, bit_len, 64, 8)
+        .chunks(64)
+        .fold(iv, |state, block| {
+            compress_block(state, block.try_into().unwrap())
+        });
+    state.iter().flat_map(|word| word.to_be_bytes()).collect()
+}
+
+fn reference_512(iv: [u64; 8], input: &[u8], bit_len: usize) -> Vec<u8> {
+    let state = pad(input, bit_len, 128, 16)
+        .chunks(128)
+        .fold(iv, |state, block| {
+            compress_block_512(state, block.try_into().unwrap())
+        });
+    state.iter().flat_map(|word| word.to_be_bytes()).collect()
+}
+
+proptest! {
+    #[test]
+    fn sha256(
+        bytes in proptest::collection::vec(any::<u8>(), MAX_BITS / 8),
+        bit_len in 0..MAX_BITS,
+    ) {
+        let input = &bytes[..bit_len.div_ceil(8)];
+        let expected = reference_256(H256, input, bit_len);
+        let digest = Sha256::new().update_bits(input, bit_len).finalize();
+        prop_assert_eq!(&digest[..], &expected[..]);
+
+        let expected = reference_256(H224, input, bit_len);
+        let digest = Sha224::new().update_bits(input, bit_len).finalize();
+        prop_assert_eq!(&digest[..], &expected[..28]);
+    }
+
+    #[test]
+    fn sha512(
+        bytes in proptest::collection::vec(any::<u8>(), MAX_BITS / 8),
+        bit_len in 0..MAX_BITS,
+    ) {
+        let input = &bytes[..bit_len.div_ceil(8)];
+        let expected = reference_512(H512, input, bit_len);
+        let digest = Sha512::new().update_bits(input, bit_len).finalize();
+        prop_assert_eq!(&digest[..], &expected[..]);
+
+        let expected = reference_512(H384, input, bit_len);
+        let digest = Sha384::new().update_bits(input, bit_len).finalize();
+        prop_assert_eq!(&digest[..], &expected[..48]);
+    }
+
+    #[test]
+    fn after_whole_bytes(
+        prefix in proptest::collection::vec(any::<u8>(), 0..200),
+        suffix in proptest::collection::vec(any::<u8>(), 1..8),
+        bits in 0..8usize,
+    ) {
+        let bit_len = (suffix.len() - 1) * 8 + bits;
+        let digest = Sha256::new()
+            .update(&prefix)
+            .update_bits(&suffix, bit_len)
+            .finalize();
+        let joined = [&prefix[..], &suffix[..]].concat();
+        let expected = reference_256(H256, &joined, prefix.len() * 8 + bit_len);
+        prop_assert_eq!(&digest[..], &expected[..]);
+    }
+}
+
+#[test]
+fn whole_bytes() {
+    let input: Vec<u8> = (0..128).map(|i| (i * 37 + 11) as u8).collect();
+    assert_eq!(
+        Sha256::new().update_bits(&input, 1024).finalize(),
+        Sha256::new().update(&input).finalize()
+    );
+    assert_eq!(
+        Sha512::new()
+            .update(&input[..3])
+            .update_bits(&input[3..], 8 * 125)
+            .finalize(),
+        Sha512::new().update(&input).finalize()
+    );
+}
+
+#[test]
+fn empty() {
+    let state = Sha256::new().update_bits(&[0xff], 0);
+    assert!(state.is_empty());
+    assert_eq!(state.finalize(), Sha256::new().finalize());
+    assert!(!Sha256::new().update_bits(&[0xff], 1).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "input data added after a partial byte")]
+fn update_after_partial_byte() {
+    let _ = Sha256::new().update_bits(&[0xff], 3).update(b"abc");
+}
+
+#[test]
+#[should_panic(expected = "input is shorter than bit_len bits")]
+fn input_too_short() {
+    let _ = Sha256::new().update_bits(&[0xff], 9);
+}
+
+#[test]
+#[should_panic(expected = "input is shorter than bit_len bits")]
+fn input_too_short_by_bytes() {
+    let _ = Sha256::new().update_bits(&[0xff], 17);
+}
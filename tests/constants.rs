@@ -0,0 +1,50 @@
+use sha2_const::constants::{H224, H256, H384, H512, H512_224, H512_256, K256, K512};
+
+#[test]
+fn round_constants() {
+    // The SHA-256 constants are the upper 32 bits of the SHA-512 constants.
+    for (k256, k512) in K256.iter().zip(&K512[..]) {
+        assert_eq!(*k256, (*k512 >> 32) as u32);
+    }
+    assert_eq!(K256[63], 0xc67178f2);
+    assert_eq!(K512[79], 0x6c44198c4a475817);
+}
+
+#[test]
+fn sha512_t_initialization_vectors() {
+    assert_eq!(H512_224, sha2_const::sha512t_iv(224));
+    assert_eq!(H512_256, sha2_const::sha512t_iv(256));
+}
+
+#[test]
+fn initialization_vectors() {
+    assert_eq!(H224[0], 0xc1059ed8);
+    assert_eq!(H256[0], 0x6a09e667);
+    assert_eq!(H384[0], 0xcbbb9d5dc1059ed8);
+    assert_eq!(H512[0], 0x6a09e667f3bcc908);
+}
+
+#[test]
+fn new_with_iv() {
+    const INPUT: &[u8] = b"abc";
+    let digest = sha2_const::Sha256::new_with_iv(H224)
+        .update(INPUT)
+        .finalize();
+    assert_eq!(digest[..28], sha2_const::sha224(INPUT));
+    let digest = sha2_const::Sha512::new_with_iv(H384)
+        .update(INPUT)
+        .finalize();
+    assert_eq!(digest[..48], sha2_const::sha384(INPUT));
+}
+
+#[test]
+fn compress_block_512() {
+    let mut block = [0; 128];
+    block[..3].copy_from_slice(b"abc");
+    block[3] = 0x80;
+    block[127] = 24;
+    assert_eq!(
+        sha2_const::compress_block_512(H512, &block),
+        sha2_const::Sha512::new().update(b"abc").finalize_words()
+    );
+}
@@ -0,0 +1,35 @@
+use sha2_const::derive::derive_permutation;
+
+#[test]
+fn permutation_256() {
+    const TABLE: [u8; 256] = derive_permutation(b"sbox");
+    assert_eq!(TABLE[..8], [40, 170, 224, 72, 6, 25, 106, 187]);
+
+    let mut seen = [false; 256];
+    for &x in TABLE.iter() {
+        assert!(!seen[x as usize]);
+        seen[x as usize] = true;
+    }
+}
+
+#[test]
+fn permutation_empty() {
+    assert_eq!(derive_permutation::<0>(b"sbox"), []);
+}
+
+#[test]
+fn short_hash_ordering() {
+    use sha2_const::derive::ShortHash;
+    use std::collections::BTreeMap;
+
+    let keys = [b"a" as &[u8], b"b", b"c", b"d"];
+    let map: BTreeMap<_, _> = keys
+        .iter()
+        .map(|&key| (ShortHash::<4>::new(key), key))
+        .collect();
+
+    assert_eq!(map.len(), keys.len());
+    assert_eq!(map[&ShortHash::new(b"c")], b"c");
+    let bytes: Vec<_> = map.keys().map(|key| *key.as_bytes()).collect();
+    assert!(bytes.windows(2).all(|pair| pair[0] < pair[1]));
+}
@@ -0,0 +1,41 @@
+use sha2_const::{digest::Digest, Sha256, Sha256d, Sha512_224};
+
+const ABC: Digest<32> = Sha256::new().update(b"abc").finalize_digest();
+
+#[test]
+fn display() {
+    assert_eq!(
+        format!("{}", ABC),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn hex() {
+    assert_eq!(format!("{:x}", ABC), hex::encode(*ABC));
+    assert_eq!(format!("{:X}", ABC), hex::encode_upper(*ABC));
+    assert_eq!(format!("{:#x}", ABC), format!("0x{}", hex::encode(*ABC)));
+    assert_eq!(
+        format!("{:#X}", ABC),
+        format!("0x{}", hex::encode_upper(*ABC))
+    );
+}
+
+#[test]
+fn bytes() {
+    let bytes = Sha256::new().update(b"abc").finalize();
+    assert_eq!(*ABC, bytes);
+    assert_eq!(ABC.as_ref(), &bytes[..]);
+    assert_eq!(ABC.into_bytes(), bytes);
+    assert_eq!(Digest::from(bytes), ABC);
+}
+
+#[test]
+fn other_hashers() {
+    let digest = Sha512_224::new().update(b"abc").finalize_digest();
+    assert_eq!(digest.len(), Sha512_224::DIGEST_SIZE);
+    assert_eq!(*digest, Sha512_224::new().update(b"abc").finalize());
+
+    let digest = Sha256d::new().update(b"abc").finalize_digest();
+    assert_eq!(*digest, Sha256d::new().update(b"abc").finalize());
+}
@@ -0,0 +1,36 @@
+/// `AlgorithmIdentifier` header up to and including the `id-sha2` arc
+/// (2.16.840.1.101.3.4.2).
+const ID_SHA2: [u8; 12] = [
+    0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02,
+];
+
+macro_rules! tests {
+    ($mod:ident, $ty:ty, $oid_suffix:literal) => {
+        mod $mod {
+            const PREFIX: &[u8] = <$ty>::DIGEST_INFO_PREFIX;
+
+            #[test]
+            fn encoded_lengths() {
+                // SEQUENCE { SEQUENCE { OID, NULL }, OCTET STRING }
+                assert_eq!(PREFIX[0], 0x30);
+                assert_eq!(PREFIX[1] as usize, PREFIX.len() - 2 + <$ty>::DIGEST_SIZE);
+                assert_eq!(PREFIX[PREFIX.len() - 2], 0x04);
+                assert_eq!(PREFIX[PREFIX.len() - 1] as usize, <$ty>::DIGEST_SIZE);
+            }
+
+            #[test]
+            fn algorithm_identifier() {
+                assert_eq!(&PREFIX[2..14], &super::ID_SHA2[..]);
+                assert_eq!(PREFIX[14], $oid_suffix);
+                assert_eq!(&PREFIX[15..17], &[0x05, 0x00]);
+            }
+        }
+    };
+}
+
+tests!(sha224, sha2_const::Sha224, 0x04);
+tests!(sha256, sha2_const::Sha256, 0x01);
+tests!(sha384, sha2_const::Sha384, 0x02);
+tests!(sha512, sha2_const::Sha512, 0x03);
+tests!(sha512_224, sha2_const::Sha512_224, 0x05);
+tests!(sha512_256, sha2_const::Sha512_256, 0x06);
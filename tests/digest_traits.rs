@@ -0,0 +1,56 @@
+#![cfg(feature = "digest-traits")]
+
+use digest::{Digest, FixedOutputReset, Reset};
+
+macro_rules! tests {
+    ($mod:ident, $ty:ty) => {
+        mod $mod {
+            use super::*;
+
+            #[test]
+            fn digest() {
+                let mut hasher = <$ty as Digest>::new();
+                Digest::update(&mut hasher, b"The quick brown fox ");
+                Digest::update(&mut hasher, b"jumps over the lazy dog");
+                let output = Digest::finalize(hasher);
+
+                let expected = <$ty>::new()
+                    .update(b"The quick brown fox jumps over the lazy dog")
+                    .finalize();
+                assert_eq!(&output[..], &expected[..]);
+                assert_eq!(<$ty as Digest>::output_size(), <$ty>::DIGEST_SIZE);
+            }
+
+            #[test]
+            fn reset() {
+                let mut hasher = <$ty as Digest>::new();
+                Digest::update(&mut hasher, b"discarded");
+                Reset::reset(&mut hasher);
+                Digest::update(&mut hasher, b"abc");
+
+                let expected = <$ty>::new().update(b"abc").finalize();
+                assert_eq!(&Digest::finalize(hasher)[..], &expected[..]);
+            }
+
+            #[test]
+            fn finalize_reset() {
+                let mut hasher = <$ty as Digest>::new();
+                Digest::update(&mut hasher, b"abc");
+                let first = FixedOutputReset::finalize_fixed_reset(&mut hasher);
+                Digest::update(&mut hasher, b"abc");
+                let second = Digest::finalize(hasher);
+
+                let expected = <$ty>::new().update(b"abc").finalize();
+                assert_eq!(&first[..], &expected[..]);
+                assert_eq!(&second[..], &expected[..]);
+            }
+        }
+    };
+}
+
+tests!(sha224, sha2_const::Sha224);
+tests!(sha256, sha2_const::Sha256);
+tests!(sha384, sha2_const::Sha384);
+tests!(sha512, sha2_const::Sha512);
+tests!(sha512_224, sha2_const::Sha512_224);
+tests!(sha512_256, sha2_const::Sha512_256);
@@ -0,0 +1,138 @@
+use sha2_const::{from_hex, pem_body_bytes, to_base32_crockford, to_base64, to_base64_url, Sha256};
+
+#[test]
+fn base32_crockford_digest() {
+    const DIGEST: [u8; 32] = Sha256::new().update(b"abc").finalize();
+    const ID: [u8; 52] = to_base32_crockford(&DIGEST);
+    assert_eq!(
+        &ID[..],
+        b"Q9W1DFWF077YMGA183F5VBH24ER06RD3JRBQN75M23ZP3WG02PPG"
+    );
+}
+
+#[test]
+fn base32_crockford_empty() {
+    assert_eq!(to_base32_crockford::<0, 0>(&[]), []);
+}
+
+#[test]
+#[should_panic]
+fn base32_crockford_wrong_length() {
+    let _: [u8; 8] = to_base32_crockford(&[0xde, 0xad, 0xbe, 0xef]);
+}
+
+#[test]
+fn base64_lengths() {
+    assert_eq!(to_base64::<0, 0>(&[]), []);
+    assert_eq!(&to_base64(b"f"), b"Zg==");
+    assert_eq!(&to_base64(b"fo"), b"Zm8=");
+    assert_eq!(&to_base64(b"foo"), b"Zm9v");
+    assert_eq!(&to_base64_url(b"f"), b"Zg");
+    assert_eq!(&to_base64_url(b"fo"), b"Zm8");
+    assert_eq!(&to_base64_url(b"foo"), b"Zm9v");
+    assert_eq!(&to_base64_url(&[0xfb, 0xff]), b"-_8");
+}
+
+#[test]
+#[should_panic]
+fn base64_wrong_length() {
+    let _: [u8; 6] = to_base64(&[0xde, 0xad, 0xbe, 0xef]);
+}
+
+#[test]
+#[should_panic]
+fn base64_url_wrong_length() {
+    let _: [u8; 8] = to_base64_url(&[0xde, 0xad, 0xbe, 0xef]);
+}
+
+#[test]
+fn hex() {
+    assert_eq!(
+        from_hex(b"00ff7fDEadBEef"),
+        [0x00, 0xff, 0x7f, 0xde, 0xad, 0xbe, 0xef]
+    );
+    assert_eq!(from_hex::<0>(b""), []);
+    assert_eq!(
+        from_hex(&sha2_const::to_hex::<4, 8>(&[1, 2, 3, 4])),
+        [1, 2, 3, 4]
+    );
+}
+
+#[test]
+#[should_panic(expected = "hex string has the wrong length")]
+fn hex_wrong_length() {
+    let _: [u8; 2] = from_hex(b"abc");
+}
+
+#[test]
+#[should_panic(expected = "invalid hex digit at index 5")]
+fn hex_invalid_digit() {
+    let _: [u8; 4] = from_hex(b"deadbxef");
+}
+
+#[test]
+fn pem_body() {
+    const PEM: &str = concat!(
+        "Subject: test\r\n",
+        "-----BEGIN TEST DATA-----\r\n",
+        "8K6s6RLPGdwcb//UHebjldrrVGLxIhlFcGvQZ0wwBesrz+In+9X1HudH\r\n",
+        "XFZ2efOuvdk=\r\n",
+        "-----END TEST DATA-----\r\n",
+    );
+    const BODY: [u8; 50] = pem_body_bytes(PEM);
+    assert_eq!(
+        hex::encode(&BODY[..]),
+        concat!(
+            "f0aeace912cf19dc1c6fffd41de6e395daeb5462f1221945706bd0674c3005eb",
+            "2bcfe227fbd5f51ee7475c567679f3aebdd9",
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "PEM body is too short")]
+fn pem_body_wrong_length() {
+    let _: [u8; 13] = pem_body_bytes("-----BEGIN X-----\naGVsbG8sIHdvcmxk\n-----END X-----\n");
+}
+
+#[test]
+#[should_panic(expected = "invalid base64 in PEM body")]
+fn pem_body_invalid() {
+    let _: [u8; 12] = pem_body_bytes("-----BEGIN X-----\naGVsbG8s*Hdvcmxk\n-----END X-----\n");
+}
+
+#[test]
+#[should_panic(expected = "missing PEM END line")]
+fn pem_body_unterminated() {
+    let _: [u8; 12] = pem_body_bytes("-----BEGIN X-----\naGVsbG8sIHdvcmxk\n");
+}
+
+#[test]
+#[should_panic(expected = "invalid base64 length in PEM body")]
+fn pem_body_dangling_character() {
+    let _: [u8; 6] = pem_body_bytes("-----BEGIN X-----\naGVsbG8sI\n-----END X-----\n");
+}
+
+#[test]
+#[should_panic(expected = "non-zero trailing bits in PEM body")]
+fn pem_body_trailing_bits() {
+    let _: [u8; 2] = pem_body_bytes("-----BEGIN X-----\naGl=\n-----END X-----\n");
+}
+
+#[test]
+#[should_panic(expected = "invalid base64 padding in PEM body")]
+fn pem_body_padding_before_final_group() {
+    let _: [u8; 4] = pem_body_bytes("-----BEGIN X-----\naGk=aGk=\n-----END X-----\n");
+}
+
+#[test]
+#[should_panic(expected = "invalid base64 padding in PEM body")]
+fn pem_body_extra_padding() {
+    let _: [u8; 2] = pem_body_bytes("-----BEGIN X-----\naGk==\n-----END X-----\n");
+}
+
+#[test]
+#[should_panic(expected = "invalid base64 padding in PEM body")]
+fn pem_body_missing_padding() {
+    let _: [u8; 2] = pem_body_bytes("-----BEGIN X-----\naGk\n-----END X-----\n");
+}
@@ -0,0 +1,25 @@
+use sha2_const::git::git_blob_oid;
+
+#[test]
+fn empty_blob() {
+    assert_eq!(
+        hex::encode(&git_blob_oid(b"")[..]),
+        "473a0f4c3be8a93681a267e3b1e9a7dcda1185436fe141f7749120a303721813"
+    );
+}
+
+#[test]
+fn hello_world() {
+    assert_eq!(
+        hex::encode(&git_blob_oid(b"hello world\n")[..]),
+        "0bd69098bd9b9cc5934a610ab65da429b525361147faa7b5b922919e9a23143d"
+    );
+}
+
+#[test]
+fn multi_digit_length() {
+    assert_eq!(
+        hex::encode(&git_blob_oid(&[0; 1000])[..]),
+        "3fb93f5ff25e070a78b2025e843191ac25db74fb9ea0f9307b92713c1d765605"
+    );
+}
@@ -0,0 +1,61 @@
+use sha2_const::{Sha256, Sha512_224};
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, BuildHasherDefault, Hash, Hasher},
+};
+
+#[test]
+fn finish() {
+    let mut hasher = Sha256::default();
+    hasher.write(b"a");
+    hasher.write(b"bc");
+    // The first eight bytes of SHA-256("abc").
+    assert_eq!(hasher.finish(), 0xba7816bf8f01cfea);
+}
+
+#[test]
+fn finish_is_not_destructive() {
+    let mut hasher = Sha512_224::default();
+    hasher.write(b"ab");
+    let first = hasher.finish();
+    assert_eq!(hasher.finish(), first);
+
+    hasher.write(b"c");
+    let digest = Sha512_224::new().update(b"abc").finalize();
+    assert_eq!(hasher.finish().to_be_bytes(), digest[..8]);
+}
+
+#[test]
+fn hash_map() {
+    let mut map: HashMap<&str, u32, BuildHasherDefault<Sha256>> = HashMap::default();
+    map.insert("one", 1);
+    map.insert("two", 2);
+    assert_eq!(map["one"], 1);
+    assert_eq!(map["two"], 2);
+
+    let mut hasher = Sha256::default();
+    "one".hash(&mut hasher);
+    assert_eq!(hasher.finish(), map.hasher().hash_one("one"));
+}
+
+#[test]
+fn debug() {
+    let hasher = Sha256::new().update(b"secret");
+    assert_eq!(format!("{:?}", hasher), "Sha256 { byte_len: 6, .. }");
+    assert_eq!(
+        format!("{:?}", sha2_const::Sha256d::new().update(b"secret")),
+        "Sha256d { inner: Sha256 { byte_len: 6, .. } }"
+    );
+    assert_eq!(
+        format!("{:?}", Sha512_224::default()),
+        "Sha512_224 { byte_len: 0, .. }"
+    );
+}
+
+#[test]
+fn sha256d_default() {
+    assert_eq!(
+        sha2_const::Sha256d::default().update(b"abc").finalize(),
+        sha2_const::Sha256d::new().update(b"abc").finalize()
+    );
+}
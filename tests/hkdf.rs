@@ -0,0 +1,78 @@
+//! Test vectors from RFC 5869, appendix A.
+
+use sha2_const::hkdf::{hkdf_expand, hkdf_extract};
+
+#[test]
+fn test_case_1() {
+    const SALT: [u8; 13] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+    const INFO: [u8; 10] = [0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9];
+    const PRK: [u8; 32] = hkdf_extract(&SALT, &[0x0b; 22]);
+    const OKM: [u8; 42] = hkdf_expand(&PRK, &INFO);
+
+    assert_eq!(
+        hex::encode(&PRK[..]),
+        "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5"
+    );
+    assert_eq!(
+        hex::encode(&OKM[..]),
+        concat!(
+            "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf",
+            "34007208d5b887185865",
+        )
+    );
+}
+
+#[test]
+fn test_case_2() {
+    let ikm: Vec<u8> = (0x00..=0x4f).collect();
+    let salt: Vec<u8> = (0x60..=0xaf).collect();
+    let info: Vec<u8> = (0xb0..=0xff).collect();
+    let prk = hkdf_extract(&salt, &ikm);
+    let okm: [u8; 82] = hkdf_expand(&prk, &info);
+
+    assert_eq!(
+        hex::encode(&prk[..]),
+        "06a6b88c5853361a06104c9ceb35b45cef760014904671014a193f40c15fc244"
+    );
+    assert_eq!(
+        hex::encode(&okm[..]),
+        concat!(
+            "b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c",
+            "59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71",
+            "cc30c58179ec3e87c14c01d5c1f3434f1d87",
+        )
+    );
+}
+
+#[test]
+fn test_case_3() {
+    const PRK: [u8; 32] = hkdf_extract(b"", &[0x0b; 22]);
+    const OKM: [u8; 42] = hkdf_expand(&PRK, b"");
+
+    assert_eq!(
+        hex::encode(&PRK[..]),
+        "19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04"
+    );
+    assert_eq!(
+        hex::encode(&OKM[..]),
+        concat!(
+            "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d",
+            "9d201395faa4b61a96c8",
+        )
+    );
+}
+
+#[test]
+fn maximum_length() {
+    let okm: [u8; 255 * 32] = hkdf_expand(&[0x01; 32], b"info");
+    assert_eq!(
+        hex::encode(&okm[255 * 32 - 32..]),
+        "c65886f6a05e3fd97dbcf57b60e003290e2afac3b2125faf9ba8a575d37a93cb"
+    );
+}
+
+#[test]
+#[should_panic]
+fn too_long() {
+    let _: [u8; 255 * 32 + 1] = hkdf_expand(&[0x01; 32], b"info");
+}
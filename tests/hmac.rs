@@ -0,0 +1,223 @@
+//! Test vectors from RFC 4231, and comparisons against the `hmac` crate.
+
+use sha2_const::hmac::{HmacSha256, PrecomputedHmacSha256};
+
+macro_rules! tests {
+    ($mod:ident, $ty:ty, $reference:ty, $tc1:expr, $tc2:expr, $tc6:expr $(,)?) => {
+        mod $mod {
+            use hmac::Mac;
+            use proptest::{arbitrary::any, prop_assert_eq, proptest};
+
+            fn known_answer(key: &[u8], data: &[u8], expected: &str) {
+                let tag = <$ty>::new(key).update(data).finalize();
+                assert_eq!(hex::encode(&tag[..]), expected);
+            }
+
+            fn reference(key: &[u8], input: &[u8]) -> Vec<u8> {
+                let mut mac = hmac::Hmac::<$reference>::new_varkey(key).unwrap();
+                mac.input(input);
+                mac.result().code().to_vec()
+            }
+
+            #[test]
+            fn test_case_1() {
+                known_answer(&[0x0b; 20], b"Hi There", $tc1);
+            }
+
+            #[test]
+            fn test_case_2() {
+                known_answer(b"Jefe", b"what do ya want for nothing?", $tc2);
+            }
+
+            #[test]
+            fn test_case_6() {
+                known_answer(
+                    &[0xaa; 131],
+                    b"Test Using Larger Than Block-Size Key - Hash Key First",
+                    $tc6,
+                );
+            }
+
+            #[test]
+            fn edge_case_keys() {
+                const BLOCK_SIZE: usize = <$ty>::BLOCK_SIZE;
+                for &len in &[
+                    0,
+                    1,
+                    BLOCK_SIZE - 1,
+                    BLOCK_SIZE,
+                    BLOCK_SIZE + 1,
+                    BLOCK_SIZE * 2,
+                ] {
+                    let key = vec![0x5a; len];
+                    let tag = <$ty>::new(&key).update(b"message").finalize();
+                    assert_eq!(
+                        &tag[..],
+                        &reference(&key, b"message")[..],
+                        "key length {}",
+                        len
+                    );
+                }
+            }
+
+            proptest! {
+                #[test]
+                fn multiple_updates(
+                    key in proptest::collection::vec(any::<u8>(), 0..<$ty>::BLOCK_SIZE * 3),
+                    inputs in proptest::collection::vec(
+                        proptest::collection::vec(any::<u8>(), 0..<$ty>::BLOCK_SIZE * 2),
+                        0..4,
+                    ),
+                ) {
+                    let tag = inputs
+                        .iter()
+                        .fold(<$ty>::new(&key), |mac, input| mac.update(input))
+                        .finalize();
+                    prop_assert_eq!(&tag[..], &reference(&key, &inputs.concat())[..]);
+                }
+            }
+        }
+    };
+}
+
+tests!(
+    sha224,
+    sha2_const::hmac::HmacSha224,
+    sha2::Sha224,
+    "896fb1128abbdf196832107cd49df33f47b4b1169912ba4f53684b22",
+    "a30e01098bc6dbbf45690f3a7e9e6d0f8bbea2a39e6148008fd05e44",
+    "95e9a0db962095adaebe9b2d6f0dbce2d499f112f2d2b7273fa6870e",
+);
+
+tests!(
+    sha256,
+    sha2_const::hmac::HmacSha256,
+    sha2::Sha256,
+    "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+    "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+    "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+);
+
+tests!(
+    sha384,
+    sha2_const::hmac::HmacSha384,
+    sha2::Sha384,
+    concat!(
+        "afd03944d84895626b0825f4ab46907f15f9dadbe4101ec682aa034c7cebc59c",
+        "faea9ea9076ede7f4af152e8b2fa9cb6",
+    ),
+    concat!(
+        "af45d2e376484031617f78d2b58a6b1b9c7ef464f5a01b47e42ec3736322445e",
+        "8e2240ca5e69e2c78b3239ecfab21649",
+    ),
+    concat!(
+        "4ece084485813e9088d2c63a041bc5b44f9ef1012a2b588f3cd11f05033ac4c6",
+        "0c2ef6ab4030fe8296248df163f44952",
+    ),
+);
+
+tests!(
+    sha512,
+    sha2_const::hmac::HmacSha512,
+    sha2::Sha512,
+    concat!(
+        "87aa7cdea5ef619d4ff0b4241a1d6cb02379f4e2ce4ec2787ad0b30545e17cde",
+        "daa833b7d6b8a702038b274eaea3f4e4be9d914eeb61f1702e696c203a126854",
+    ),
+    concat!(
+        "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea250554",
+        "9758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737",
+    ),
+    concat!(
+        "80b24263c7c1a3ebb71493c1dd7be8b49b46d1f41b4aeec1121b013783f8f352",
+        "6b56d037e05f2598bd0fd2215d6a1e5295e64f73f63f0aec8b915a985d786598",
+    ),
+);
+
+tests!(
+    sha512_224,
+    sha2_const::hmac::HmacSha512_224,
+    sha2::Sha512Trunc224,
+    "b244ba01307c0e7a8ccaad13b1067a4cf6b961fe0c6a20bda3d92039",
+    "4a530b31a79ebcce36916546317c45f247d83241dfb818fd37254bde",
+    "29bef8ce88b54d4226c3c7718ea9e32ace2429026f089e38cea9aeda",
+);
+
+tests!(
+    sha512_256,
+    sha2_const::hmac::HmacSha512_256,
+    sha2::Sha512Trunc256,
+    "9f9126c3d9c3c330d760425ca8a217e31feae31bfe70196ff81642b868402eab",
+    "6df7b24630d5ccb2ee335407081a87188c221489768fa2020513b2d593359456",
+    "87123c45f7c537a404f8f47cdbedda1fc9bec60eeb971982ce7ef10e774e6539",
+);
+
+#[test]
+fn precomputed_known_answers() {
+    let cases: [(&[u8], &[u8], &str); 3] = [
+        (
+            &[0x0b; 20],
+            b"Hi There",
+            "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+        ),
+        (
+            b"Jefe",
+            b"what do ya want for nothing?",
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+        ),
+        (
+            &[0xaa; 131],
+            b"Test Using Larger Than Block-Size Key - Hash Key First",
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+        ),
+    ];
+    for &(key, data, expected) in &cases {
+        let tag = PrecomputedHmacSha256::new(key).mac(data);
+        assert_eq!(hex::encode(&tag[..]), expected);
+    }
+}
+
+#[test]
+fn precomputed_long_keys() {
+    for &len in &[65, 100, 131, 200] {
+        let key: Vec<u8> = (0..len).map(|i| i as u8).collect();
+        assert_eq!(
+            PrecomputedHmacSha256::new(&key).mac(b"message"),
+            HmacSha256::new(&key).update(b"message").finalize(),
+            "key length {}",
+            len
+        );
+    }
+}
+
+#[test]
+fn precomputed_in_const() {
+    const KEY: PrecomputedHmacSha256 = PrecomputedHmacSha256::new(b"Jefe");
+    const TAG: [u8; 32] = KEY.mac(b"what do ya want for nothing?");
+    assert_eq!(
+        TAG,
+        HmacSha256::new(b"Jefe")
+            .update(b"what do ya want for nothing?")
+            .finalize()
+    );
+    assert_eq!(
+        hex::encode(&TAG[..]),
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+}
+
+/// The inputs are from the NIST CAVP HMAC_DRBG test vectors (SHA-256, no
+/// prediction resistance, COUNT = 0), with the nonce appended to the entropy.
+#[test]
+fn drbg_seed_cavp() {
+    let entropy = hex::decode(concat!(
+        "ca851911349384bffe89de1cbdc46e6831e44d34a4fb935ee285dd14b71a7488",
+        "659ba96c601dc69fc902940805ec0ca8",
+    ))
+    .unwrap();
+    let v = sha2_const::hmac::drbg_seed(&entropy, b"");
+    assert_eq!(
+        hex::encode(&v[..]),
+        "e75855f93b971ac468d200992e211960202d53cf08852ef86772d6490bfb53f9"
+    );
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "std")]
+
+use sha2_const::{Sha256, Sha384};
+use std::io::{self, Write};
+
+#[test]
+fn copy() {
+    let input = vec![0x5a; 1000];
+    let mut hasher = Sha384::new();
+    let copied = io::copy(&mut &input[..], &mut hasher).unwrap();
+    assert_eq!(copied, 1000);
+    assert_eq!(hasher.finalize(), Sha384::new().update(&input).finalize());
+}
+
+#[test]
+fn write() {
+    let mut hasher = Sha256::new();
+    assert_eq!(Write::write(&mut hasher, b"ab").unwrap(), 2);
+    write!(hasher, "c").unwrap();
+    hasher.flush().unwrap();
+    assert_eq!(hasher.finalize(), sha2_const::sha256(b"abc"));
+}
+
+/// A reader that returns short reads, interrupted reads, and then optionally
+/// an error.
+struct Flaky<'a> {
+    input: &'a [u8],
+    calls: usize,
+    fail: bool,
+}
+
+impl io::Read for Flaky<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.calls += 1;
+        if self.calls.is_multiple_of(3) {
+            return Err(io::ErrorKind::Interrupted.into());
+        }
+        if self.input.is_empty() && self.fail {
+            return Err(io::ErrorKind::BrokenPipe.into());
+        }
+        let len = buf.len().min(self.input.len()).min(self.calls % 7 + 1);
+        buf[..len].copy_from_slice(&self.input[..len]);
+        self.input = &self.input[len..];
+        Ok(len)
+    }
+}
+
+#[test]
+fn update_reader() {
+    let input: Vec<u8> = (0..=255).collect();
+    let reader = Flaky {
+        input: &input,
+        calls: 0,
+        fail: false,
+    };
+    let digest = Sha256::new()
+        .update(b"prefix")
+        .update_reader(reader)
+        .unwrap()
+        .finalize();
+    assert_eq!(
+        digest,
+        Sha256::new().update(b"prefix").update(&input).finalize()
+    );
+}
+
+#[test]
+fn update_reader_error() {
+    let reader = Flaky {
+        input: b"abc",
+        calls: 0,
+        fail: true,
+    };
+    let err = Sha384::new().update_reader(reader).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+}
@@ -0,0 +1,49 @@
+use sha2_const::merkle::merkle_root;
+
+/// Decodes a transaction ID or Merkle root, which Bitcoin displays with its
+/// bytes reversed.
+fn reversed(s: &str) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    bytes.copy_from_slice(&hex::decode(s).unwrap());
+    bytes.reverse();
+    bytes
+}
+
+/// The transactions of block 100000.
+fn block_100000() -> [[u8; 32]; 4] {
+    [
+        reversed("8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87"),
+        reversed("fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4"),
+        reversed("6359f0868171b1d194cbee1af2f16ea598ae8fad666d9b012c8ed2b79a236ec4"),
+        reversed("e9a66845e05d5abc0ad04ec80f774a7e585c6e8db975962d069a522137b80c1d"),
+    ]
+}
+
+#[test]
+fn bitcoin_block() {
+    assert_eq!(
+        merkle_root(block_100000()),
+        reversed("f3e94742aca4b5ef85488dc37c06c3282295ffec960994b2c0d5ac2a25a95766")
+    );
+}
+
+#[test]
+fn odd_leaves() {
+    let [a, b, c, _] = block_100000();
+    assert_eq!(
+        merkle_root([a, b, c]),
+        reversed("fa435470825de273081dcc706b25514c936fa6dc80ab965ce6970d68ddd0b553")
+    );
+    assert_eq!(merkle_root([a, b, c]), merkle_root([a, b, c, c]));
+}
+
+#[test]
+fn single_leaf() {
+    let [a, ..] = block_100000();
+    assert_eq!(merkle_root([a]), a);
+}
+
+#[test]
+fn no_leaves() {
+    assert_eq!(merkle_root([]), [0; 32]);
+}
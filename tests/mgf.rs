@@ -0,0 +1,22 @@
+use sha2_const::mgf::mgf1_sha256;
+
+#[test]
+fn known_answer() {
+    const MASK: [u8; 50] = mgf1_sha256(b"bar");
+    assert_eq!(
+        hex::encode(&MASK[..]),
+        concat!(
+            "382576a7841021cc28fc4c0948753fb8312090cea942ea4c4e735d10dc724b15",
+            "5f9f6069f289d61daca0cb814502ef04eae1",
+        )
+    );
+}
+
+#[test]
+fn prefix() {
+    let long: [u8; 64] = mgf1_sha256(b"seed");
+    let short: [u8; 33] = mgf1_sha256(b"seed");
+    let empty: [u8; 0] = mgf1_sha256(b"seed");
+    assert_eq!(short[..], long[..33]);
+    assert_eq!(empty, []);
+}
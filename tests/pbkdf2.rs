@@ -0,0 +1,64 @@
+//! The test vectors from RFC 6070, adapted for PBKDF2-HMAC-SHA256.
+
+use sha2_const::pbkdf2::pbkdf2_sha256;
+
+macro_rules! known_answer_test {
+    ($name:ident, $password:expr, $salt:expr, $iterations:expr, $len:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let dk: [u8; $len] = pbkdf2_sha256($password, $salt, $iterations);
+            assert_eq!(hex::encode(&dk), $expected);
+        }
+    };
+}
+
+known_answer_test!(
+    one_iteration,
+    b"password",
+    b"salt",
+    1,
+    32,
+    "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b"
+);
+
+known_answer_test!(
+    two_iterations,
+    b"password",
+    b"salt",
+    2,
+    32,
+    "ae4d0c95af6b46d32d0adff928f06dd02a303f8ef3c251dfd6e2d85a95474c43"
+);
+
+known_answer_test!(
+    many_iterations,
+    b"password",
+    b"salt",
+    4096,
+    32,
+    "c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a"
+);
+
+known_answer_test!(
+    multiple_blocks,
+    b"passwordPASSWORDpassword",
+    b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+    4096,
+    40,
+    "348c89dbcbd32b2f32d814b8116e84cf2b17347ebc1800181c4e2a1fb8dd53e1c635518c7dac47e9"
+);
+
+known_answer_test!(
+    embedded_nul,
+    b"pass\0word",
+    b"sa\0lt",
+    4096,
+    16,
+    "89b69d0516f829893c696226650a8687"
+);
+
+#[test]
+#[should_panic]
+fn zero_iterations() {
+    let _: [u8; 32] = pbkdf2_sha256(b"password", b"salt", 0);
+}
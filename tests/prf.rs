@@ -0,0 +1,34 @@
+//! The TLS 1.2 PRF test vector for SHA-256 published on the IETF TLS mailing
+//! list.
+
+use sha2_const::prf::tls12_prf_sha256;
+
+#[test]
+fn known_answer() {
+    const SECRET: [u8; 16] = [
+        0x9b, 0xbe, 0x43, 0x6b, 0xa9, 0x40, 0xf0, 0x17, 0xb1, 0x76, 0x52, 0x84, 0x9a, 0x71, 0xdb,
+        0x35,
+    ];
+    const SEED: [u8; 16] = [
+        0xa0, 0xba, 0x9f, 0x93, 0x6c, 0xda, 0x31, 0x18, 0x27, 0xa6, 0xf7, 0x96, 0xff, 0xd5, 0x19,
+        0x8c,
+    ];
+    const OUTPUT: [u8; 100] = tls12_prf_sha256(&SECRET, b"test label", &SEED);
+
+    assert_eq!(
+        hex::encode(&OUTPUT[..]),
+        concat!(
+            "e3f229ba727be17b8d122620557cd453c2aab21d07c3d495329b52d4e61edb5a",
+            "6b301791e90d35c9c9a46b4e14baf9af0fa022f7077def17abfd3797c0564bab",
+            "4fbc91666e9def9b97fce34f796789baa48082d122ee42c5a72e5a5110fff701",
+            "87347b66",
+        )
+    );
+}
+
+#[test]
+fn prefix() {
+    let long: [u8; 100] = tls12_prf_sha256(b"secret", b"label", b"seed");
+    let short: [u8; 33] = tls12_prf_sha256(b"secret", b"label", b"seed");
+    assert_eq!(short[..], long[..33]);
+}
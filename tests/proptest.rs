@@ -1,7 +1,9 @@
 macro_rules! tests {
-    ($mod:ident, $ty:ty, $reference:ty) => {
+    ($mod:ident, $ty:ty, $oneshot:path, $reference:ty) => {
         mod $mod {
-            use proptest::{arbitrary::any, prop_assert_eq, proptest, strategy::Strategy};
+            use proptest::{
+                arbitrary::any, prop_assert, prop_assert_eq, proptest, strategy::Strategy,
+            };
             use sha2::Digest;
 
             fn hash_input() -> impl Strategy<Value = Vec<u8>> {
@@ -16,6 +18,13 @@ macro_rules! tests {
                     prop_assert_eq!(&digest[..], &expected[..]);
                 }
 
+                #[test]
+                fn oneshot(input in hash_input()) {
+                    let digest = $oneshot(&input);
+                    let expected = <$reference>::digest(&input);
+                    prop_assert_eq!(&digest[..], &expected[..]);
+                }
+
                 #[test]
                 fn multiple_updates(inputs in proptest::array::uniform4(hash_input())) {
                     let digest = inputs
@@ -25,14 +34,321 @@ macro_rules! tests {
                     let expected = <$reference>::digest(&inputs.concat());
                     prop_assert_eq!(&digest[..], &expected[..]);
                 }
+
+                #[test]
+                fn update_all(inputs in proptest::collection::vec(hash_input(), 0..8)) {
+                    let parts: Vec<&[u8]> = inputs.iter().map(Vec::as_slice).collect();
+                    let digest = <$ty>::new().update_all(&parts).finalize();
+                    let expected = <$reference>::digest(&inputs.concat());
+                    prop_assert_eq!(&digest[..], &expected[..]);
+                }
+
+                #[test]
+                fn update_byte(prefix in hash_input(), input in hash_input()) {
+                    let digest = input
+                        .iter()
+                        .fold(<$ty>::new().update(&prefix), |state, &b| state.update_byte(b))
+                        .finalize();
+                    let expected = <$reference>::digest(&[&prefix[..], &input[..]].concat());
+                    prop_assert_eq!(&digest[..], &expected[..]);
+                }
+
+                #[test]
+                fn update_with_len_prefix(input in hash_input()) {
+                    let digest = <$ty>::new().update_with_len_prefix(&input).finalize();
+                    let mut prefixed = ((input.len() as u64) * 8).to_be_bytes().to_vec();
+                    prefixed.extend_from_slice(&input);
+                    let expected = <$reference>::digest(&prefixed);
+                    prop_assert_eq!(&digest[..], &expected[..]);
+                }
+
+                #[test]
+                fn update_cstr(input in hash_input()) {
+                    let digest = <$ty>::new().update_cstr(&input).finalize();
+                    let len = input.iter().position(|&b| b == 0).unwrap_or(input.len());
+                    let expected = <$reference>::digest(&input[..len]);
+                    prop_assert_eq!(&digest[..], &expected[..]);
+                }
+
+                #[test]
+                fn update_padded(
+                    input in hash_input(),
+                    pad_to in 1..<$ty>::BLOCK_SIZE * 3,
+                    pad_byte in any::<u8>(),
+                ) {
+                    let digest = <$ty>::new().update_padded(&input, pad_to, pad_byte).finalize();
+                    let mut padded = input.clone();
+                    while padded.len() % pad_to != 0 {
+                        padded.push(pad_byte);
+                    }
+                    let expected = <$reference>::digest(&padded);
+                    prop_assert_eq!(&digest[..], &expected[..]);
+                }
+
+                #[test]
+                fn update_unsigned(
+                    a in any::<u8>(),
+                    b in any::<u32>(),
+                    c in any::<u64>(),
+                    d in any::<u16>(),
+                ) {
+                    let digest = <$ty>::new()
+                        .update_u8(a)
+                        .update_u16_be(d)
+                        .update_u16_le(d)
+                        .update_u32_be(b)
+                        .update_u32_le(b)
+                        .update_u64_be(c)
+                        .update_u64_le(c)
+                        .finalize();
+                    let expected = <$reference>::new()
+                        .chain([a])
+                        .chain(d.to_be_bytes())
+                        .chain(d.to_le_bytes())
+                        .chain(b.to_be_bytes())
+                        .chain(b.to_le_bytes())
+                        .chain(c.to_be_bytes())
+                        .chain(c.to_le_bytes())
+                        .result();
+                    prop_assert_eq!(&digest[..], &expected[..]);
+                }
+
+                #[test]
+                fn update_u128(input in hash_input(), n in any::<u128>()) {
+                    let be = <$ty>::new().update(&input).update_u128_be(n).finalize();
+                    let le = <$ty>::new().update(&input).update_u128_le(n).finalize();
+                    let expected_be =
+                        <$reference>::digest(&[&input[..], &n.to_be_bytes()].concat());
+                    let expected_le =
+                        <$reference>::digest(&[&input[..], &n.to_le_bytes()].concat());
+                    prop_assert_eq!(&be[..], &expected_be[..]);
+                    prop_assert_eq!(&le[..], &expected_le[..]);
+                }
+
+                #[test]
+                fn update_signed(
+                    a in any::<i8>(),
+                    b in any::<i16>(),
+                    c in any::<i32>(),
+                    d in any::<i64>(),
+                ) {
+                    let digest = <$ty>::new()
+                        .update_i8(a)
+                        .update_i16_be(b)
+                        .update_i16_le(b)
+                        .update_i32_be(c)
+                        .update_i32_le(c)
+                        .update_i64_be(d)
+                        .update_i64_le(d)
+                        .finalize();
+                    let expected = <$reference>::new()
+                        .chain(a.to_be_bytes())
+                        .chain(b.to_be_bytes())
+                        .chain(b.to_le_bytes())
+                        .chain(c.to_be_bytes())
+                        .chain(c.to_le_bytes())
+                        .chain(d.to_be_bytes())
+                        .chain(d.to_le_bytes())
+                        .result();
+                    prop_assert_eq!(&digest[..], &expected[..]);
+                }
+
+                #[test]
+                fn update_ascii_lower(input in hash_input()) {
+                    let digest = <$ty>::new().update_ascii_lower(&input).finalize();
+                    let expected = <$reference>::digest(&input.to_ascii_lowercase());
+                    prop_assert_eq!(&digest[..], &expected[..]);
+                }
+
+                #[test]
+                fn update_rstrip(input in hash_input(), trailing in "[ \t\n\r]{0,8}") {
+                    let padded = [&input[..], trailing.as_bytes()].concat();
+                    let digest = <$ty>::new().update_rstrip(&padded).finalize();
+                    let len = input
+                        .iter()
+                        .rposition(|b| !b" \t\n\r".contains(b))
+                        .map_or(0, |i| i + 1);
+                    let expected = <$reference>::digest(&input[..len]);
+                    prop_assert_eq!(&digest[..], &expected[..]);
+                }
+
+                #[test]
+                fn byte_len(inputs in proptest::array::uniform4(hash_input())) {
+                    let state = inputs
+                        .iter()
+                        .fold(<$ty>::new(), |state, input| state.update(input));
+                    let expected = inputs.iter().map(Vec::len).sum::<usize>();
+                    prop_assert_eq!(state.byte_len(), expected as u64);
+                    prop_assert_eq!(state.len(), expected as u64);
+                    prop_assert_eq!(state.is_empty(), expected == 0);
+                    prop_assert_eq!(state.buffered_bytes(), expected % <$ty>::BLOCK_SIZE);
+                }
+
+                #[test]
+                fn padded_len(len in 0..u64::MAX / 2) {
+                    let block_size = <$ty>::BLOCK_SIZE as u64;
+                    let length_size = block_size / 8;
+                    let padded = <$ty>::padded_len(len);
+                    prop_assert_eq!(padded % block_size, 0);
+                    prop_assert!(padded >= len + 1 + length_size);
+                    prop_assert!(padded - block_size < len + 1 + length_size);
+                }
+
+                #[test]
+                fn finalize_hex(input in hash_input()) {
+                    let expected = <$reference>::digest(&input);
+                    let lower = <$ty>::new().update(&input).finalize_hex();
+                    let upper = <$ty>::new().update(&input).finalize_hex_upper();
+                    prop_assert_eq!(&lower[..], hex::encode(&expected).into_bytes());
+                    prop_assert_eq!(&upper[..], hex::encode_upper(&expected).into_bytes());
+                }
+
+                #[test]
+                fn finalize_base64(input in hash_input()) {
+                    let expected = <$reference>::digest(&input);
+                    let standard = <$ty>::new().update(&input).finalize_base64();
+                    let url = <$ty>::new().update(&input).finalize_base64_url();
+                    prop_assert_eq!(&standard[..], base64::encode(&expected).into_bytes());
+                    prop_assert_eq!(
+                        &url[..],
+                        base64::encode_config(&expected, base64::URL_SAFE_NO_PAD).into_bytes()
+                    );
+                }
+
+                #[test]
+                fn verify(input in hash_input(), flip in 0..<$ty>::DIGEST_SIZE * 8) {
+                    let mut expected = <$ty>::new().update(&input).finalize();
+                    prop_assert!(<$ty>::new().update(&input).verify(&expected));
+                    expected[flip / 8] ^= 1 << (flip % 8);
+                    prop_assert!(!<$ty>::new().update(&input).verify(&expected));
+                }
+
+                #[test]
+                fn reset(discarded in hash_input(), input in hash_input()) {
+                    let mut state = <$ty>::new().update(&discarded);
+                    state.reset();
+                    prop_assert!(state.is_empty());
+                    prop_assert_eq!(state.buffered_bytes(), 0);
+                    let digest = state.update(&input).finalize();
+                    let expected = <$reference>::digest(&input);
+                    prop_assert_eq!(&digest[..], &expected[..]);
+                }
+
+                #[test]
+                fn finalize_truncated(input in hash_input()) {
+                    let expected = <$reference>::digest(&input);
+                    let short: [u8; 5] = <$ty>::new().update(&input).finalize_truncated();
+                    let full: [u8; <$ty>::DIGEST_SIZE] =
+                        <$ty>::new().update(&input).finalize_truncated();
+                    prop_assert_eq!(&short[..], &expected[..5]);
+                    prop_assert_eq!(&full[..], &expected[..]);
+                }
+
+                #[test]
+                fn finalize_reset(inputs in proptest::array::uniform3(hash_input())) {
+                    let mut state = <$ty>::new();
+                    for input in &inputs {
+                        state = state.update(input);
+                        let digest = state.finalize_reset();
+                        let expected = <$reference>::digest(input);
+                        prop_assert_eq!(&digest[..], &expected[..]);
+                        prop_assert!(state.is_empty());
+                    }
+                }
+
+                #[test]
+                fn finalize_into(input in hash_input(), offset in 0..<$ty>::BLOCK_SIZE) {
+                    let mut out = vec![0xff; offset + <$ty>::DIGEST_SIZE + 1];
+                    <$ty>::new().update(&input).finalize_into(&mut out, offset);
+                    let expected = <$reference>::digest(&input);
+                    prop_assert!(out[..offset].iter().all(|&b| b == 0xff));
+                    prop_assert_eq!(&out[offset..offset + <$ty>::DIGEST_SIZE], &expected[..]);
+                    prop_assert_eq!(out[offset + <$ty>::DIGEST_SIZE], 0xff);
+                }
+            }
+        }
+    };
+}
+
+tests!(sha224, sha2_const::Sha224, sha2_const::sha224, sha2::Sha224);
+tests!(sha256, sha2_const::Sha256, sha2_const::sha256, sha2::Sha256);
+tests!(sha384, sha2_const::Sha384, sha2_const::sha384, sha2::Sha384);
+tests!(sha512, sha2_const::Sha512, sha2_const::sha512, sha2::Sha512);
+tests!(
+    sha512_224,
+    sha2_const::Sha512_224,
+    sha2_const::sha512_224,
+    sha2::Sha512Trunc224
+);
+tests!(
+    sha512_256,
+    sha2_const::Sha512_256,
+    sha2_const::sha512_256,
+    sha2::Sha512Trunc256
+);
+
+macro_rules! words_tests {
+    ($mod:ident, $ty:ty) => {
+        mod $mod {
+            use proptest::{arbitrary::any, prop_assert_eq, proptest};
+
+            proptest! {
+                #[test]
+                fn finalize_words(
+                    input in proptest::collection::vec(any::<u8>(), 0..<$ty>::BLOCK_SIZE * 4),
+                ) {
+                    let words = <$ty>::new().update(&input).finalize_words();
+                    let bytes: Vec<u8> = words
+                        .iter()
+                        .flat_map(|word| word.to_be_bytes().to_vec())
+                        .collect();
+                    let expected = <$ty>::new().update(&input).finalize();
+                    prop_assert_eq!(&bytes[..], &expected[..]);
+                }
+
+                #[test]
+                fn midstate(
+                    prefix in proptest::collection::vec(any::<u8>(), 0..<$ty>::BLOCK_SIZE * 4),
+                    suffix in proptest::collection::vec(any::<u8>(), 0..<$ty>::BLOCK_SIZE * 2),
+                ) {
+                    let prefix = &prefix[..prefix.len() / <$ty>::BLOCK_SIZE * <$ty>::BLOCK_SIZE];
+                    let (state, length) = <$ty>::new().update(prefix).into_midstate();
+                    prop_assert_eq!(length as usize, prefix.len() * 8);
+
+                    let digest = <$ty>::from_midstate(state, length).update(&suffix).finalize();
+                    let expected = <$ty>::new().update(prefix).update(&suffix).finalize();
+                    prop_assert_eq!(&digest[..], &expected[..]);
+
+                    let mut resumed = <$ty>::from_midstate(state, length).update(&suffix);
+                    resumed.reset();
+                    let digest = resumed.update(&suffix).finalize();
+                    prop_assert_eq!(&digest[..], &expected[..]);
+                }
             }
         }
     };
 }
 
-tests!(sha224, sha2_const::Sha224, sha2::Sha224);
-tests!(sha256, sha2_const::Sha256, sha2::Sha256);
-tests!(sha384, sha2_const::Sha384, sha2::Sha384);
-tests!(sha512, sha2_const::Sha512, sha2::Sha512);
-tests!(sha512_224, sha2_const::Sha512_224, sha2::Sha512Trunc224);
-tests!(sha512_256, sha2_const::Sha512_256, sha2::Sha512Trunc256);
+words_tests!(sha256_words, sha2_const::Sha256);
+words_tests!(sha512_words, sha2_const::Sha512);
+
+mod sha256d {
+    use proptest::{arbitrary::any, prop_assert_eq, proptest};
+    use sha2::Digest;
+
+    proptest! {
+        #[test]
+        fn double_hash(
+            inputs in proptest::array::uniform4(
+                proptest::collection::vec(any::<u8>(), 0..sha2_const::Sha256d::BLOCK_SIZE * 2),
+            ),
+        ) {
+            let digest = inputs
+                .iter()
+                .fold(sha2_const::Sha256d::new(), |state, input| state.update(input))
+                .finalize();
+            let expected = sha2::Sha256::digest(&sha2::Sha256::digest(&inputs.concat()));
+            prop_assert_eq!(&digest[..], &expected[..]);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use proptest::{arbitrary::any, prop_assert_eq, proptest, strategy::Strategy};
+use sha2::Digest;
+
+const IMAGE_SIZE: usize = 300;
+
+fn run() -> impl Strategy<Value = (usize, usize)> {
+    (0..IMAGE_SIZE)
+        .prop_flat_map(|offset| (proptest::strategy::Just(offset), 0..=IMAGE_SIZE - offset))
+}
+
+proptest! {
+    #[test]
+    fn hash_with_hole(
+        input in proptest::collection::vec(any::<u8>(), IMAGE_SIZE),
+        hole_offset in 0..IMAGE_SIZE,
+        hole_len in 0..IMAGE_SIZE,
+    ) {
+        let mut image = [0; IMAGE_SIZE];
+        image.copy_from_slice(&input);
+        let hole_len = hole_len.min(IMAGE_SIZE - hole_offset);
+
+        let digest = sha2_const::region::hash_with_hole(&image, hole_offset, hole_len);
+
+        let mut zeroed = image;
+        zeroed[hole_offset..hole_offset + hole_len].fill(0);
+        let expected = sha2::Sha256::digest(&zeroed);
+        prop_assert_eq!(&digest[..], &expected[..]);
+    }
+
+    #[test]
+    fn hash_iovec(
+        base in proptest::collection::vec(any::<u8>(), IMAGE_SIZE),
+        runs in proptest::collection::vec(run(), 0..8),
+    ) {
+        let digest = sha2_const::region::hash_iovec(&base, &runs);
+
+        let gathered: Vec<u8> = runs
+            .iter()
+            .flat_map(|&(offset, len)| base[offset..offset + len].iter().copied())
+            .collect();
+        let expected = sha2::Sha256::digest(&gathered);
+        prop_assert_eq!(&digest[..], &expected[..]);
+    }
+
+    #[test]
+    fn sha256_of_window(
+        data in proptest::collection::vec(any::<u8>(), IMAGE_SIZE),
+        (start, window) in run(),
+    ) {
+        let digest = sha2_const::region::sha256_of_window(&data, start, window);
+        let expected = sha2::Sha256::digest(&data[start..start + window]);
+        prop_assert_eq!(&digest[..], &expected[..]);
+    }
+
+    #[test]
+    fn hash_ring(
+        buf in proptest::collection::vec(any::<u8>(), IMAGE_SIZE),
+        head in 0..IMAGE_SIZE,
+        len in 0..=IMAGE_SIZE,
+    ) {
+        let digest = sha2_const::region::hash_ring(&buf, head, len);
+
+        let mut rotated = buf.clone();
+        rotated.rotate_left(head);
+        let expected = sha2::Sha256::digest(&rotated[..len]);
+        prop_assert_eq!(&digest[..], &expected[..]);
+    }
+}
+
+#[test]
+fn hash_ring_empty() {
+    let empty = sha2::Sha256::digest(b"");
+    assert_eq!(sha2_const::region::hash_ring(&[], 0, 0)[..], empty[..]);
+    assert_eq!(sha2_const::region::hash_ring(b"abc", 3, 0)[..], empty[..]);
+}
+
+#[test]
+#[should_panic]
+fn hash_ring_empty_buffer() {
+    let _ = sha2_const::region::hash_ring(&[], 0, 1);
+}
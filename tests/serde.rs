@@ -0,0 +1,44 @@
+#![cfg(feature = "serde")]
+
+use sha2_const::{digest::Digest, Sha256};
+
+const ABC: Digest<32> = Sha256::new().update(b"abc").finalize_digest();
+const ABC_HEX: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+#[test]
+fn json() {
+    let json = serde_json::to_string(&ABC).unwrap();
+    assert_eq!(json, format!("\"{}\"", ABC_HEX));
+    assert_eq!(serde_json::from_str::<Digest<32>>(&json).unwrap(), ABC);
+}
+
+#[test]
+fn json_uppercase() {
+    let json = format!("\"{}\"", ABC_HEX.to_uppercase());
+    assert_eq!(serde_json::from_str::<Digest<32>>(&json).unwrap(), ABC);
+}
+
+#[test]
+fn json_wrong_length() {
+    let json = format!("\"{}\"", &ABC_HEX[..62]);
+    assert!(serde_json::from_str::<Digest<32>>(&json).is_err());
+}
+
+#[test]
+fn json_invalid_hex() {
+    let json = format!("\"{}zz\"", &ABC_HEX[..62]);
+    assert!(serde_json::from_str::<Digest<32>>(&json).is_err());
+}
+
+#[test]
+fn bincode() {
+    let bytes = bincode::serialize(&ABC).unwrap();
+    assert_eq!(&bytes[8..], &ABC[..]);
+    assert_eq!(bincode::deserialize::<Digest<32>>(&bytes).unwrap(), ABC);
+}
+
+#[test]
+fn bincode_wrong_length() {
+    let bytes = bincode::serialize(&[0u8; 31][..]).unwrap();
+    assert!(bincode::deserialize::<Digest<32>>(&bytes).is_err());
+}
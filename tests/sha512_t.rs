@@ -0,0 +1,69 @@
+use sha2_const::{sha512_t, sha512t_iv, Sha512, Sha512_224, Sha512_256};
+
+#[test]
+fn sha512_224_iv() {
+    assert_eq!(
+        sha512t_iv(224),
+        [
+            0x8c3d37c819544da2,
+            0x73e1996689dcd4d6,
+            0x1dfab7ae32ff9c82,
+            0x679dd514582f9fcf,
+            0x0f6d2b697bd44da8,
+            0x77e36f7304c48942,
+            0x3f9d85a86a1d36c8,
+            0x1112e6ad91d692a1,
+        ]
+    );
+}
+
+#[test]
+fn sha512_256_iv() {
+    assert_eq!(
+        sha512t_iv(256),
+        [
+            0x22312194fc2bf72c,
+            0x9f555fa3c84c64c2,
+            0x2393b86b6f53b151,
+            0x963877195940eabd,
+            0x96283ee2a88effe3,
+            0xbe5e1e2553863992,
+            0x2b0199fc2c85b8aa,
+            0x0eb72ddc81c52ca2,
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn sha512_384_iv() {
+    let _ = sha512t_iv(384);
+}
+
+#[test]
+fn standard_truncations() {
+    const INPUT: &[u8] = b"The quick brown fox jumps over the lazy dog";
+    assert_eq!(
+        sha512_t::<224, 28>(INPUT),
+        Sha512_224::new().update(INPUT).finalize()
+    );
+    assert_eq!(
+        sha512_t::<256, 32>(INPUT),
+        Sha512_256::new().update(INPUT).finalize()
+    );
+}
+
+#[test]
+fn nonstandard_truncation() {
+    const DIGEST: [u8; 24] = sha512_t::<192, 24>(b"abc");
+    let full = Sha512::from_midstate(sha512t_iv(192), 0)
+        .update(b"abc")
+        .finalize();
+    assert_eq!(DIGEST[..], full[..24]);
+}
+
+#[test]
+#[should_panic]
+fn mismatched_size() {
+    let _ = sha512_t::<256, 28>(b"abc");
+}
@@ -0,0 +1,114 @@
+use sha2_const::Sha256;
+
+#[test]
+fn printable_ascii() {
+    let input = b" !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`{|}~";
+    assert_eq!(
+        Sha256::new().update_printable_ascii(input).finalize(),
+        Sha256::new().update(input).finalize()
+    );
+}
+
+#[test]
+#[should_panic(expected = "non-printable byte at index 3")]
+fn printable_ascii_control() {
+    let _ = Sha256::new().update_printable_ascii(b"key\n");
+}
+
+#[test]
+#[should_panic(expected = "non-printable byte at index 0")]
+fn printable_ascii_delete() {
+    let _ = Sha256::new().update_printable_ascii(b"\x7fkey");
+}
+
+#[test]
+fn ascii_only() {
+    let input = "user_id";
+    assert_eq!(
+        Sha256::new().update_str_ascii_only(input).finalize(),
+        Sha256::new().update(input.as_bytes()).finalize()
+    );
+}
+
+#[test]
+#[should_panic(expected = "non-ASCII byte at index 3")]
+fn ascii_only_non_ascii() {
+    let _ = Sha256::new().update_str_ascii_only("caf\u{e9}");
+}
+
+#[test]
+#[should_panic]
+fn midstate_partial_block() {
+    let _ = Sha256::new().update(b"abc").into_midstate();
+}
+
+#[test]
+#[should_panic]
+fn midstate_partial_length() {
+    let _ = Sha256::from_midstate([0; 8], 24);
+}
+
+#[test]
+#[should_panic]
+fn finalize_into_too_short() {
+    let mut out = [0; 40];
+    Sha256::new().finalize_into(&mut out, 9);
+}
+
+#[test]
+#[should_panic]
+fn finalize_into_offset_overflow() {
+    let mut out = [0; 40];
+    Sha256::new().finalize_into(&mut out, usize::MAX);
+}
+
+#[test]
+#[should_panic]
+fn finalize_truncated_too_long() {
+    let _: [u8; 33] = Sha256::new().finalize_truncated();
+}
+
+const DATA: &[u8] = b"embedded data";
+const PINNED: [u8; 32] =
+    sha2_const::from_hex(b"a774d9e7bd9d1a78b757e3ab4c0e0f59e8cc1dc0a2b43e9774cb19ee4cae0984");
+
+// Fails the build if DATA does not match PINNED.
+const _: () = assert!(Sha256::new().update(DATA).verify(&PINNED));
+
+#[test]
+fn verify_in_const() {
+    let tampered = Sha256::new().update(b"embedded dat4");
+    assert!(!tampered.verify(&PINNED));
+}
+
+#[test]
+fn padded_len() {
+    assert_eq!(Sha256::padded_len(0), 64);
+    assert_eq!(Sha256::padded_len(55), 64);
+    assert_eq!(Sha256::padded_len(56), 128);
+    assert_eq!(sha2_const::Sha384::padded_len(111), 128);
+    assert_eq!(sha2_const::Sha384::padded_len(112), 256);
+}
+
+#[test]
+fn padded_len_boundary() {
+    // The longest messages whose padded lengths fit in a u64.
+    let max = u64::MAX - 63 - 9;
+    assert_eq!(Sha256::padded_len(max), u64::MAX - 63);
+    assert_eq!(
+        sha2_const::Sha512::padded_len(u64::MAX - 127 - 17),
+        u64::MAX - 127
+    );
+}
+
+#[test]
+#[should_panic(expected = "padded length overflows u64")]
+fn padded_len_overflow() {
+    let _ = Sha256::padded_len(u64::MAX - 63 - 8);
+}
+
+#[test]
+#[should_panic(expected = "padded length overflows u64")]
+fn padded_len_overflow_max() {
+    let _ = sha2_const::Sha512::padded_len(u64::MAX);
+}